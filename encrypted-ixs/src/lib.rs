@@ -8,6 +8,7 @@ mod circuits {
     pub struct VoteStats {
         yes: u64,
         no: u64,
+        overflowed: bool,  // Set if any counter saturated
     }
 
     /// Represents a single encrypted vote.
@@ -15,11 +16,28 @@ mod circuits {
         vote: bool,
     }
 
+    /// Largest number of options a multi-option poll may have
+    const MAX_POLL_OPTIONS: usize = 8;
+    /// Reported in place of an option id that is not revealed
+    const NO_OPTION: u8 = 255;
+
+    /// Tracks the encrypted per-option tallies for a multi-option poll.
+    pub struct OptionVoteStats {
+        counts: [u64; MAX_POLL_OPTIONS],
+        overflowed: bool,  // Set if any counter saturated
+    }
+
+    /// Represents a single encrypted vote for one option of a multi-option poll.
+    pub struct OptionVote {
+        option: u8,
+    }
+
     /// Tracks aggregated opinion statistics (1-5 ratings)
     pub struct OpinionStats {
-        total_responses: u32,
-        sum_ratings: u32,
-        rating_counts: [u32; 5], // Count for each rating 1-5
+        total_responses: u64,
+        sum_ratings: u64,
+        rating_counts: [u64; 5], // Count for each rating 1-5
+        overflowed: bool,        // Set if any counter saturated
     }
 
     /// Represents a single encrypted opinion response
@@ -27,16 +45,76 @@ mod circuits {
         rating: u8,  // 1-5 rating
     }
 
+    /// A respondent's eligibility attribute, encrypted by the survey authority that issued it
+    pub struct AttributeCredential {
+        attribute: u8,  // e.g. department id or tenure band, 0-63
+    }
+
+    /// Counts responses dropped because the eligibility predicate did not hold
+    pub struct RejectedResponses {
+        count: u64,
+    }
+
     /// Represents a single encrypted feedback response
     pub struct FeedbackResponse {
         rating: u8,  // 1-5 rating for posts
     }
 
+    /// A respondent's private standing relative to the survey aggregate
+    pub struct PersonalComparison {
+        position: u8,           // 0 = below mean, 1 = at mean, 2 = above mean
+        percentile_bucket: u8,  // 0-9, share of respondents rated lower in 10% steps
+    }
+
     /// Tracks aggregated feedback statistics for an opinion post
     pub struct FeedbackStats {
-        total_feedback: u32,
-        sum_ratings: u32,
-        rating_counts: [u32; 5],  // Counts for 1-5 ratings
+        total_feedback: u64,
+        sum_ratings: u64,
+        rating_counts: [u64; 5],  // Counts for 1-5 ratings
+        overflowed: bool,         // Set if any counter saturated
+    }
+
+    /// Tracks the encrypted aggregate of probability forecasts for a question
+    pub struct ForecastStats {
+        total_forecasts: u64,
+        sum_probability: u64,
+    }
+
+    /// Represents a single encrypted probability forecast
+    pub struct ForecastInput {
+        probability: u8,  // 0-100 percent
+    }
+
+    /// A participant's private Brier score for a resolved forecast
+    pub struct ForecastScore {
+        probability: u8,
+        brier_score: u16,  // (p - o)^2 with p, o in percent; 0 is perfect, 10000 is worst
+    }
+
+    /// Adds `amount` to `value`, saturating at `u64::MAX`.
+    ///
+    /// Returns the new value and whether saturation occurred, so callers can fold it
+    /// into the encrypted overflow flag instead of silently wrapping.
+    fn saturating_add(value: u64, amount: u64) -> (u64, bool) {
+        let overflow = value > u64::MAX - amount;
+        let sum = if overflow { u64::MAX } else { value + amount };
+        (sum, overflow)
+    }
+
+    /// Adds a single vote to the tallies, saturating on overflow
+    fn record_vote(mut vote_stats: VoteStats, vote: bool) -> VoteStats {
+        // Increment appropriate counter based on vote value
+        if vote {
+            let (yes, overflow) = saturating_add(vote_stats.yes, 1);
+            vote_stats.yes = yes;
+            vote_stats.overflowed = vote_stats.overflowed || overflow;
+        } else {
+            let (no, overflow) = saturating_add(vote_stats.no, 1);
+            vote_stats.no = no;
+            vote_stats.overflowed = vote_stats.overflowed || overflow;
+        }
+
+        vote_stats
     }
 
     /// Initializes encrypted vote counters for a new poll.
@@ -45,7 +123,11 @@ mod circuits {
     /// The counters remain encrypted and can only be updated through MPC operations.
     #[instruction]
     pub fn init_vote_stats(mxe: Mxe) -> Enc<Mxe, VoteStats> {
-        let vote_stats = VoteStats { yes: 0, no: 0 };
+        let vote_stats = VoteStats {
+            yes: 0,
+            no: 0,
+            overflowed: false,
+        };
         mxe.from_arcis(vote_stats)
     }
 
@@ -67,65 +149,270 @@ mod circuits {
         vote_stats_ctxt: Enc<Mxe, VoteStats>,
     ) -> Enc<Mxe, VoteStats> {
         let user_vote = vote_ctxt.to_arcis();
-        let mut vote_stats = vote_stats_ctxt.to_arcis();
-
-        // Increment appropriate counter based on vote value
-        if user_vote.vote {
-            vote_stats.yes += 1;
-        } else {
-            vote_stats.no += 1;
-        }
+        let vote_stats = record_vote(vote_stats_ctxt.to_arcis(), user_vote.vote);
 
         vote_stats_ctxt.owner.from_arcis(vote_stats)
     }
 
     /// Reveals the final result of the poll by comparing vote tallies.
     ///
-    /// Decrypts the vote counters and determines whether the majority voted yes or no.
-    /// Only the final result (majority decision) is revealed, not the actual vote counts.
+    /// Decrypts the vote counters and determines whether the yes share exceeds the
+    /// passing threshold `threshold_num / threshold_den`, i.e.
+    /// `yes * den > (yes + no) * num`. A threshold of 1/2 is a simple majority.
+    /// Only the final result is revealed, not the actual vote counts.
     ///
     /// # Arguments
     /// * `vote_stats_ctxt` - Encrypted vote tallies to be revealed
+    /// * `min_ballots` - Quorum; fewer ballots than this withholds the result
+    /// * `threshold_num`, `threshold_den` - Passing fraction of the ballots cast
     ///
     /// # Returns
-    /// * `true` if more people voted yes than no
-    /// * `false` if more people voted no than yes (or tie)
+    /// * whether the quorum was met
+    /// * `true` if quorum was met and the yes share exceeds the threshold
+    /// * `false` if the yes share is at or below the threshold, or quorum was not met
+    /// * whether either counter saturated, in which case the result may be unreliable
     #[instruction]
-    pub fn reveal_result(vote_stats_ctxt: Enc<Mxe, VoteStats>) -> bool {
+    pub fn reveal_result(
+        vote_stats_ctxt: Enc<Mxe, VoteStats>,
+        min_ballots: u64,
+        threshold_num: u16,
+        threshold_den: u16,
+    ) -> (bool, bool, bool) {
         let vote_stats = vote_stats_ctxt.to_arcis();
-        (vote_stats.yes > vote_stats.no).reveal()
+        let (total, _) = saturating_add(vote_stats.yes, vote_stats.no);
+        let quorum_met = total >= min_ballots;
+        // Widen before multiplying so large tallies cannot wrap
+        let above_threshold = (vote_stats.yes as u128) * (threshold_den as u128)
+            > (total as u128) * (threshold_num as u128);
+        // Without quorum the outcome is withheld rather than revealed
+        let passed = quorum_met && above_threshold;
+        (
+            quorum_met.reveal(),
+            passed.reveal(),
+            vote_stats.overflowed.reveal(),
+        )
     }
 
-    /// Initializes encrypted opinion statistics for a new opinion poll
-    #[instruction]
-    pub fn init_opinion_stats(mxe: Mxe) -> Enc<Mxe, OpinionStats> {
-        let stats = OpinionStats {
+    /// Returns zeroed opinion statistics
+    fn empty_opinion_stats() -> OpinionStats {
+        OpinionStats {
             total_responses: 0,
             sum_ratings: 0,
             rating_counts: [0; 5],
-        };
-        mxe.from_arcis(stats)
+            overflowed: false,
+        }
+    }
+
+    /// Adds a single rating to opinion statistics, saturating on overflow.
+    ///
+    /// Ratings outside 1-5 are counted as nothing, so a malformed ciphertext can neither
+    /// skew the sum nor push the average above 5.
+    fn record_opinion_rating(mut stats: OpinionStats, rating: u8) -> OpinionStats {
+        let valid = rating >= 1 && rating <= 5;
+        let counted: u64 = if valid { 1 } else { 0 };
+        let amount: u64 = if valid { rating as u64 } else { 0 };
+
+        // Update total responses
+        let (total, total_overflow) = saturating_add(stats.total_responses, counted);
+        let (sum, sum_overflow) = saturating_add(stats.sum_ratings, amount);
+        stats.total_responses = total;
+        stats.sum_ratings = sum;
+        stats.overflowed = stats.overflowed || total_overflow || sum_overflow;
+
+        // Update rating counts (convert 1-5 to 0-4 index)
+        if valid {
+            let index = (rating - 1) as usize;
+            let (count, overflow) = saturating_add(stats.rating_counts[index], 1);
+            stats.rating_counts[index] = count;
+            stats.overflowed = stats.overflowed || overflow;
+        }
+
+        stats
+    }
+
+    /// Initializes empty per-option tallies for a multi-option poll.
+    #[instruction]
+    pub fn init_option_vote_stats(mxe: Mxe) -> Enc<Mxe, OptionVoteStats> {
+        mxe.from_arcis(OptionVoteStats {
+            counts: [0; MAX_POLL_OPTIONS],
+            overflowed: false,
+        })
+    }
+
+    /// Adds an encrypted vote to the tally of the chosen option.
+    ///
+    /// Votes for an option at or above `option_count` are ignored.
+    #[instruction]
+    pub fn vote_option(
+        vote_ctxt: Enc<Shared, OptionVote>,
+        option_count: u8,
+        stats_ctxt: Enc<Mxe, OptionVoteStats>,
+    ) -> Enc<Mxe, OptionVoteStats> {
+        let vote = vote_ctxt.to_arcis();
+        let mut stats = stats_ctxt.to_arcis();
+
+        for i in 0..MAX_POLL_OPTIONS {
+            if vote.option == i as u8 && (i as u8) < option_count {
+                let (count, overflow) = saturating_add(stats.counts[i], 1);
+                stats.counts[i] = count;
+                stats.overflowed = stats.overflowed || overflow;
+            }
+        }
+
+        stats_ctxt.owner.from_arcis(stats)
     }
 
-    /// Processes an encrypted opinion response and updates the statistics
+    /// Reveals the outcome of a multi-option poll without revealing any tallies.
+    ///
+    /// An option wins outright if it holds more than half of the ballots. Otherwise, in
+    /// runoff mode, the ids of the two leading options are revealed so a runoff can be
+    /// held between them; outside runoff mode the plurality leader is revealed alone.
+    /// Ties go to the lower option id. `option_count` is at least 2.
+    ///
+    /// # Returns
+    /// * whether the leading option holds a majority
+    /// * the leading option id
+    /// * the runner-up option id, or `NO_OPTION` unless a runoff is needed
+    /// * whether any counter saturated, in which case the result may be unreliable
+    #[instruction]
+    pub fn reveal_option_result(
+        stats_ctxt: Enc<Mxe, OptionVoteStats>,
+        option_count: u8,
+        runoff: bool,
+    ) -> (bool, u8, u8, bool) {
+        let stats = stats_ctxt.to_arcis();
+
+        let mut leader = 0u8;
+        let mut leader_count = stats.counts[0];
+        let mut runner_up = 1u8;
+        let mut runner_up_count = stats.counts[1];
+        if runner_up_count > leader_count {
+            leader = 1;
+            leader_count = stats.counts[1];
+            runner_up = 0;
+            runner_up_count = stats.counts[0];
+        }
+        let (mut total, _) = saturating_add(stats.counts[0], stats.counts[1]);
+
+        for i in 2..MAX_POLL_OPTIONS {
+            if (i as u8) < option_count {
+                let count = stats.counts[i];
+                if count > leader_count {
+                    runner_up = leader;
+                    runner_up_count = leader_count;
+                    leader = i as u8;
+                    leader_count = count;
+                } else if count > runner_up_count {
+                    runner_up = i as u8;
+                    runner_up_count = count;
+                }
+                let (sum, _) = saturating_add(total, count);
+                total = sum;
+            }
+        }
+
+        let has_majority = (leader_count as u128) * 2 > total as u128;
+        let runner_up = if !has_majority && runoff { runner_up } else { NO_OPTION };
+
+        (
+            has_majority.reveal(),
+            leader.reveal(),
+            runner_up.reveal(),
+            stats.overflowed.reveal(),
+        )
+    }
+
+    /// Initializes encrypted opinion statistics for a new opinion poll.
+    ///
+    /// Returns the all-time statistics, the current-window accumulator and the counter of
+    /// ineligible responses, all empty.
+    #[instruction]
+    pub fn init_opinion_stats(
+        mxe: Mxe,
+    ) -> (
+        Enc<Mxe, OpinionStats>,
+        Enc<Mxe, OpinionStats>,
+        Enc<Mxe, RejectedResponses>,
+    ) {
+        (
+            mxe.from_arcis(empty_opinion_stats()),
+            mxe.from_arcis(empty_opinion_stats()),
+            mxe.from_arcis(RejectedResponses { count: 0 }),
+        )
+    }
+
+    /// Processes an encrypted opinion response and updates the statistics.
+    ///
+    /// The response is added to both the all-time statistics and the current window.
     #[instruction]
     pub fn submit_opinion(
         response_ctxt: Enc<Shared, OpinionResponse>,
         stats_ctxt: Enc<Mxe, OpinionStats>,
-    ) -> Enc<Mxe, OpinionStats> {
+        window_ctxt: Enc<Mxe, OpinionStats>,
+    ) -> (Enc<Mxe, OpinionStats>, Enc<Mxe, OpinionStats>) {
         let response = response_ctxt.to_arcis();
-        let mut stats = stats_ctxt.to_arcis();
+        let stats = record_opinion_rating(stats_ctxt.to_arcis(), response.rating);
+        let window = record_opinion_rating(window_ctxt.to_arcis(), response.rating);
 
-        // Update total responses
-        stats.total_responses += 1;
-        stats.sum_ratings += response.rating as u32;
+        (
+            stats_ctxt.owner.from_arcis(stats),
+            window_ctxt.owner.from_arcis(window),
+        )
+    }
 
-        // Update rating counts (convert 1-5 to 0-4 index)
-        if response.rating >= 1 && response.rating <= 5 {
-            stats.rating_counts[(response.rating - 1) as usize] += 1;
+    /// Processes an encrypted opinion response from a survey with an eligibility predicate.
+    ///
+    /// The attribute comes from a credential encrypted by the survey authority, not from the
+    /// respondent. `allowed_attributes` is a plaintext bitmask set by the survey authority:
+    /// the response counts only if bit `attribute` is set. Otherwise the statistics are left
+    /// unchanged and the rejected counter is incremented instead, so the attribute never
+    /// leaves MPC.
+    #[instruction]
+    pub fn submit_eligible_opinion(
+        response_ctxt: Enc<Shared, OpinionResponse>,
+        credential_ctxt: Enc<Shared, AttributeCredential>,
+        allowed_attributes: u64,
+        stats_ctxt: Enc<Mxe, OpinionStats>,
+        window_ctxt: Enc<Mxe, OpinionStats>,
+        rejected_ctxt: Enc<Mxe, RejectedResponses>,
+    ) -> (
+        Enc<Mxe, OpinionStats>,
+        Enc<Mxe, OpinionStats>,
+        Enc<Mxe, RejectedResponses>,
+    ) {
+        let response = response_ctxt.to_arcis();
+        let credential = credential_ctxt.to_arcis();
+
+        // Look the attribute up bit by bit; attributes outside 0-63 are never eligible
+        let mut eligible = false;
+        for i in 0..64 {
+            if credential.attribute == i as u8 && (allowed_attributes >> i) & 1 == 1 {
+                eligible = true;
+            }
         }
 
-        stats_ctxt.owner.from_arcis(stats)
+        let mut stats = stats_ctxt.to_arcis();
+        let mut window = window_ctxt.to_arcis();
+        let mut rejected = rejected_ctxt.to_arcis();
+        if eligible {
+            stats = record_opinion_rating(stats, response.rating);
+            window = record_opinion_rating(window, response.rating);
+        } else {
+            let (count, _) = saturating_add(rejected.count, 1);
+            rejected.count = count;
+        }
+
+        (
+            stats_ctxt.owner.from_arcis(stats),
+            window_ctxt.owner.from_arcis(window),
+            rejected_ctxt.owner.from_arcis(rejected),
+        )
+    }
+
+    /// Reveals how many responses were rejected by the eligibility predicate
+    #[instruction]
+    pub fn reveal_rejected_responses(rejected_ctxt: Enc<Mxe, RejectedResponses>) -> u64 {
+        rejected_ctxt.to_arcis().count.reveal()
     }
 
     /// Reveals the aggregated opinion statistics
@@ -135,42 +422,486 @@ mod circuits {
         stats.reveal()
     }
 
-    /// Initializes encrypted feedback statistics for an opinion post
+    /// Closes the current opinion window.
+    ///
+    /// Returns the window's statistics, re-encrypted for storage in an epoch account,
+    /// and a fresh empty window. The all-time statistics are untouched.
     #[instruction]
-    pub fn init_feedback_stats(mxe: Mxe) -> Enc<Mxe, FeedbackStats> {
-        let stats = FeedbackStats {
+    pub fn rotate_opinion_window(
+        window_ctxt: Enc<Mxe, OpinionStats>,
+    ) -> (Enc<Mxe, OpinionStats>, Enc<Mxe, OpinionStats>) {
+        let window = window_ctxt.to_arcis();
+        (
+            window_ctxt.owner.from_arcis(window),
+            window_ctxt.owner.from_arcis(empty_opinion_stats()),
+        )
+    }
+
+    /// Reveals the opinion statistics captured for a single epoch
+    #[instruction]
+    pub fn reveal_opinion_epoch_stats(stats_ctxt: Enc<Mxe, OpinionStats>) -> OpinionStats {
+        let stats = stats_ctxt.to_arcis();
+        stats.reveal()
+    }
+
+    /// Initializes the encrypted statistics for one period of a recurring survey
+    #[instruction]
+    pub fn init_pulse_stats(mxe: Mxe) -> Enc<Mxe, OpinionStats> {
+        mxe.from_arcis(empty_opinion_stats())
+    }
+
+    /// Adds an encrypted response to the statistics of the current survey period
+    #[instruction]
+    pub fn submit_pulse_opinion(
+        response_ctxt: Enc<Shared, OpinionResponse>,
+        stats_ctxt: Enc<Mxe, OpinionStats>,
+    ) -> Enc<Mxe, OpinionStats> {
+        let response = response_ctxt.to_arcis();
+        let stats = record_opinion_rating(stats_ctxt.to_arcis(), response.rating);
+        stats_ctxt.owner.from_arcis(stats)
+    }
+
+    /// Reveals only whether the average opinion rating meets a threshold.
+    ///
+    /// The threshold is a plaintext value supplied by the survey authority, scaled by 10
+    /// to match `average_rating` (e.g. 35 means 3.5). The comparison is done without
+    /// division, and neither the average nor the distribution is revealed. A survey with
+    /// no responses never meets the threshold.
+    ///
+    /// # Returns
+    /// * `true` if the average rating is at or above the threshold
+    /// * `false` otherwise
+    /// * the threshold that was evaluated
+    #[instruction]
+    pub fn reveal_opinion_threshold(
+        stats_ctxt: Enc<Mxe, OpinionStats>,
+        threshold: u8,
+    ) -> (bool, u8) {
+        let stats = stats_ctxt.to_arcis();
+        let above = stats.total_responses > 0
+            && (stats.sum_ratings as u128) * 10
+                >= (threshold as u128) * (stats.total_responses as u128);
+        (above.reveal(), threshold)
+    }
+
+    /// Compares the average ratings of two opinion surveys without revealing either.
+    ///
+    /// Means are compared by cross-multiplication so no division happens inside MPC.
+    /// The margin is scaled by 10 like `average_rating`; a difference at or below the
+    /// margin is reported as not significant.
+    ///
+    /// # Returns
+    /// * `0` if the difference is within the margin
+    /// * `1` if survey A has the higher average
+    /// * `2` if survey B has the higher average
+    /// * the margin that was evaluated
+    #[instruction]
+    pub fn compare_opinion_stats(
+        stats_a_ctxt: Enc<Mxe, OpinionStats>,
+        stats_b_ctxt: Enc<Mxe, OpinionStats>,
+        margin: u8,
+    ) -> (u8, u8) {
+        let a = stats_a_ctxt.to_arcis();
+        let b = stats_b_ctxt.to_arcis();
+
+        // mean_a - mean_b > margin / 10  <=>  10 * sum_a * n_b > 10 * sum_b * n_a + margin * n_a * n_b
+        let n_a = a.total_responses as u128;
+        let n_b = b.total_responses as u128;
+        let scaled_a = (a.sum_ratings as u128) * n_b * 10;
+        let scaled_b = (b.sum_ratings as u128) * n_a * 10;
+        let slack = (margin as u128) * n_a * n_b;
+
+        let mut outcome: u8 = 0;
+        if scaled_a > scaled_b + slack {
+            outcome = 1;
+        }
+        if scaled_b > scaled_a + slack {
+            outcome = 2;
+        }
+
+        (outcome.reveal(), margin)
+    }
+
+    /// Re-encrypts the aggregated opinion statistics to the survey owner.
+    ///
+    /// Unlike `reveal_opinion_stats`, nothing is published in plaintext: the statistics
+    /// are handed back as a ciphertext only the owner's x25519 key can decrypt.
+    #[instruction]
+    pub fn reencrypt_opinion_stats(
+        owner: Shared,
+        stats_ctxt: Enc<Mxe, OpinionStats>,
+    ) -> Enc<Shared, OpinionStats> {
+        let stats = stats_ctxt.to_arcis();
+        owner.from_arcis(stats)
+    }
+
+    /// Tells a respondent how their rating compares to the survey aggregate.
+    ///
+    /// The respondent supplies their own rating again under their shared key. The result
+    /// is encrypted back to that same key, so neither the aggregate nor the respondent's
+    /// standing is ever revealed publicly.
+    #[instruction]
+    pub fn compare_opinion_response(
+        response_ctxt: Enc<Shared, OpinionResponse>,
+        stats_ctxt: Enc<Mxe, OpinionStats>,
+    ) -> Enc<Shared, PersonalComparison> {
+        let response = response_ctxt.to_arcis();
+        let stats = stats_ctxt.to_arcis();
+
+        // rating vs sum / total, compared without division
+        let scaled_rating = (response.rating as u128) * (stats.total_responses as u128);
+        let sum = stats.sum_ratings as u128;
+        let mut position: u8 = 1;
+        if scaled_rating < sum {
+            position = 0;
+        }
+        if scaled_rating > sum {
+            position = 2;
+        }
+
+        // Number of responses strictly below this rating
+        let mut below: u128 = 0;
+        for i in 0..5 {
+            if (i as u8) + 1 < response.rating {
+                below += stats.rating_counts[i] as u128;
+            }
+        }
+
+        let total = stats.total_responses as u128;
+        let mut percentile_bucket: u8 = 0;
+        for bucket in 1..10 {
+            if below * 10 >= (bucket as u128) * total && total > 0 {
+                percentile_bucket = bucket as u8;
+            }
+        }
+
+        let comparison = PersonalComparison {
+            position,
+            percentile_bucket,
+        };
+        response_ctxt.owner.from_arcis(comparison)
+    }
+
+    /// Returns zeroed feedback statistics
+    fn empty_feedback_stats() -> FeedbackStats {
+        FeedbackStats {
             total_feedback: 0,
             sum_ratings: 0,
             rating_counts: [0; 5],
-        };
-        mxe.from_arcis(stats)
+            overflowed: false,
+        }
     }
 
-    /// Processes an encrypted feedback response and updates the statistics
+    /// Adds a single rating to feedback statistics, saturating on overflow.
+    ///
+    /// Ratings outside 1-5 are counted as nothing, so a malformed ciphertext can neither
+    /// skew the sum nor push the average above 5.
+    fn record_feedback_rating(mut stats: FeedbackStats, rating: u8) -> FeedbackStats {
+        let valid = rating >= 1 && rating <= 5;
+        let counted: u64 = if valid { 1 } else { 0 };
+        let amount: u64 = if valid { rating as u64 } else { 0 };
+
+        // Update total feedback
+        let (total, total_overflow) = saturating_add(stats.total_feedback, counted);
+        let (sum, sum_overflow) = saturating_add(stats.sum_ratings, amount);
+        stats.total_feedback = total;
+        stats.sum_ratings = sum;
+        stats.overflowed = stats.overflowed || total_overflow || sum_overflow;
+
+        // Update rating counts (1-5 to 0-4)
+        if valid {
+            let index = (rating - 1) as usize;
+            let (count, overflow) = saturating_add(stats.rating_counts[index], 1);
+            stats.rating_counts[index] = count;
+            stats.overflowed = stats.overflowed || overflow;
+        }
+
+        stats
+    }
+
+    /// Initializes encrypted feedback statistics for an opinion post.
+    ///
+    /// Returns the all-time statistics and the current-window accumulator, both empty.
+    #[instruction]
+    pub fn init_feedback_stats(mxe: Mxe) -> (Enc<Mxe, FeedbackStats>, Enc<Mxe, FeedbackStats>) {
+        (
+            mxe.from_arcis(empty_feedback_stats()),
+            mxe.from_arcis(empty_feedback_stats()),
+        )
+    }
+
+    /// Processes an encrypted feedback response and updates the statistics.
+    ///
+    /// The response is added to both the all-time statistics and the current window.
     #[instruction]
     pub fn submit_feedback(
         feedback_ctxt: Enc<Shared, FeedbackResponse>,
         stats_ctxt: Enc<Mxe, FeedbackStats>,
-    ) -> Enc<Mxe, FeedbackStats> {
+        window_ctxt: Enc<Mxe, FeedbackStats>,
+    ) -> (Enc<Mxe, FeedbackStats>, Enc<Mxe, FeedbackStats>) {
         let feedback = feedback_ctxt.to_arcis();
-        let mut stats = stats_ctxt.to_arcis();
+        let stats = record_feedback_rating(stats_ctxt.to_arcis(), feedback.rating);
+        let window = record_feedback_rating(window_ctxt.to_arcis(), feedback.rating);
 
-        // Update total feedback
-        stats.total_feedback += 1;
-        stats.sum_ratings += feedback.rating as u32;
+        (
+            stats_ctxt.owner.from_arcis(stats),
+            window_ctxt.owner.from_arcis(window),
+        )
+    }
 
-        // Update rating counts (1-5 to 0-4)
-        if feedback.rating >= 1 && feedback.rating <= 5 {
-            stats.rating_counts[(feedback.rating - 1) as usize] += 1;
+    /// Reveals the aggregated feedback statistics
+    #[instruction]
+    pub fn reveal_feedback_stats(stats_ctxt: Enc<Mxe, FeedbackStats>) -> FeedbackStats {
+        let stats = stats_ctxt.to_arcis();
+        stats.reveal()
+    }
+
+    /// Re-encrypts the aggregated feedback statistics to the post author.
+    #[instruction]
+    pub fn reencrypt_feedback_stats(
+        owner: Shared,
+        stats_ctxt: Enc<Mxe, FeedbackStats>,
+    ) -> Enc<Shared, FeedbackStats> {
+        let stats = stats_ctxt.to_arcis();
+        owner.from_arcis(stats)
+    }
+
+    /// Closes the current feedback window, like `rotate_opinion_window`.
+    #[instruction]
+    pub fn rotate_feedback_window(
+        window_ctxt: Enc<Mxe, FeedbackStats>,
+    ) -> (Enc<Mxe, FeedbackStats>, Enc<Mxe, FeedbackStats>) {
+        let window = window_ctxt.to_arcis();
+        (
+            window_ctxt.owner.from_arcis(window),
+            window_ctxt.owner.from_arcis(empty_feedback_stats()),
+        )
+    }
+
+    /// Reveals the feedback statistics captured for a single epoch
+    #[instruction]
+    pub fn reveal_feedback_epoch_stats(stats_ctxt: Enc<Mxe, FeedbackStats>) -> FeedbackStats {
+        let stats = stats_ctxt.to_arcis();
+        stats.reveal()
+    }
+
+    /// Reveals only whether the average feedback rating meets a threshold.
+    ///
+    /// Same comparison as `reveal_opinion_threshold`, applied to a post's feedback.
+    #[instruction]
+    pub fn reveal_feedback_threshold(
+        stats_ctxt: Enc<Mxe, FeedbackStats>,
+        threshold: u8,
+    ) -> (bool, u8) {
+        let stats = stats_ctxt.to_arcis();
+        let above = stats.total_feedback > 0
+            && (stats.sum_ratings as u128) * 10
+                >= (threshold as u128) * (stats.total_feedback as u128);
+        (above.reveal(), threshold)
+    }
+
+    /// Initializes encrypted forecast aggregates for a new forecasting question
+    #[instruction]
+    pub fn init_forecast_stats(mxe: Mxe) -> Enc<Mxe, ForecastStats> {
+        let stats = ForecastStats {
+            total_forecasts: 0,
+            sum_probability: 0,
+        };
+        mxe.from_arcis(stats)
+    }
+
+    /// Adds an encrypted probability forecast to the running aggregate.
+    ///
+    /// Probabilities above 100 are clamped so a malformed ciphertext cannot skew the mean.
+    #[instruction]
+    pub fn submit_forecast(
+        forecast_ctxt: Enc<Shared, ForecastInput>,
+        stats_ctxt: Enc<Mxe, ForecastStats>,
+    ) -> Enc<Mxe, ForecastStats> {
+        let forecast = forecast_ctxt.to_arcis();
+        let mut stats = stats_ctxt.to_arcis();
+
+        let mut probability = forecast.probability;
+        if probability > 100 {
+            probability = 100;
         }
 
+        // Saturating, like the other counters; a u64 count cannot realistically fill up
+        let (total, _) = saturating_add(stats.total_forecasts, 1);
+        let (sum, _) = saturating_add(stats.sum_probability, probability as u64);
+        stats.total_forecasts = total;
+        stats.sum_probability = sum;
+
         stats_ctxt.owner.from_arcis(stats)
     }
 
-    /// Reveals the aggregated feedback statistics
+    /// Reveals the forecast count and probability sum so the mean forecast can be published
     #[instruction]
-    pub fn reveal_feedback_stats(stats_ctxt: Enc<Mxe, FeedbackStats>) -> FeedbackStats {
+    pub fn reveal_forecast_mean(stats_ctxt: Enc<Mxe, ForecastStats>) -> ForecastStats {
         let stats = stats_ctxt.to_arcis();
         stats.reveal()
     }
+
+    /// Scores a participant's forecast against the resolved outcome.
+    ///
+    /// The full score (including the original probability) is encrypted back to the
+    /// participant. Only a bucket of the Brier score (0-4, 2000 points wide, best first) is
+    /// revealed for the pseudonymous leaderboard, since the exact score together with the
+    /// public outcome would give away the probability.
+    #[instruction]
+    pub fn score_forecast(
+        forecast_ctxt: Enc<Shared, ForecastInput>,
+        outcome: bool,
+    ) -> (Enc<Shared, ForecastScore>, u8) {
+        let forecast = forecast_ctxt.to_arcis();
+
+        let mut probability = forecast.probability;
+        if probability > 100 {
+            probability = 100;
+        }
+
+        let target: u16 = if outcome { 100 } else { 0 };
+        let p = probability as u16;
+        let diff = if p > target { p - target } else { target - p };
+        let brier_score = diff * diff;
+
+        // Count the 2000-point steps the score reaches, without dividing
+        let mut bucket: u8 = 0;
+        for step in 1..5u16 {
+            if brier_score >= step * 2000 {
+                bucket += 1;
+            }
+        }
+
+        let score = ForecastScore {
+            probability,
+            brier_score,
+        };
+        (forecast_ctxt.owner.from_arcis(score), bucket.reveal())
+    }
+
+    /// Aggregates a batch of queued votes into the tallies in a single computation.
+    ///
+    /// The batch always has eight slots; only the first `count` hold real ballots and the
+    /// rest are ignored, so callers can pad unused slots with zeroed ciphertexts.
+    #[instruction]
+    pub fn aggregate_vote_batch(
+        ballot_0: Enc<Shared, UserVote>,
+        ballot_1: Enc<Shared, UserVote>,
+        ballot_2: Enc<Shared, UserVote>,
+        ballot_3: Enc<Shared, UserVote>,
+        ballot_4: Enc<Shared, UserVote>,
+        ballot_5: Enc<Shared, UserVote>,
+        ballot_6: Enc<Shared, UserVote>,
+        ballot_7: Enc<Shared, UserVote>,
+        count: u8,
+        vote_stats_ctxt: Enc<Mxe, VoteStats>,
+    ) -> Enc<Mxe, VoteStats> {
+        let ballots = [
+            ballot_0.to_arcis(),
+            ballot_1.to_arcis(),
+            ballot_2.to_arcis(),
+            ballot_3.to_arcis(),
+            ballot_4.to_arcis(),
+            ballot_5.to_arcis(),
+            ballot_6.to_arcis(),
+            ballot_7.to_arcis(),
+        ];
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+
+        for i in 0..8 {
+            let updated = record_vote(vote_stats, ballots[i].vote);
+            if (i as u8) < count {
+                vote_stats = updated;
+            }
+        }
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Aggregates a batch of queued opinion responses, like `aggregate_vote_batch`
+    #[instruction]
+    pub fn aggregate_opinion_batch(
+        ballot_0: Enc<Shared, OpinionResponse>,
+        ballot_1: Enc<Shared, OpinionResponse>,
+        ballot_2: Enc<Shared, OpinionResponse>,
+        ballot_3: Enc<Shared, OpinionResponse>,
+        ballot_4: Enc<Shared, OpinionResponse>,
+        ballot_5: Enc<Shared, OpinionResponse>,
+        ballot_6: Enc<Shared, OpinionResponse>,
+        ballot_7: Enc<Shared, OpinionResponse>,
+        count: u8,
+        stats_ctxt: Enc<Mxe, OpinionStats>,
+        window_ctxt: Enc<Mxe, OpinionStats>,
+    ) -> (Enc<Mxe, OpinionStats>, Enc<Mxe, OpinionStats>) {
+        let ballots = [
+            ballot_0.to_arcis(),
+            ballot_1.to_arcis(),
+            ballot_2.to_arcis(),
+            ballot_3.to_arcis(),
+            ballot_4.to_arcis(),
+            ballot_5.to_arcis(),
+            ballot_6.to_arcis(),
+            ballot_7.to_arcis(),
+        ];
+        let mut stats = stats_ctxt.to_arcis();
+        let mut window = window_ctxt.to_arcis();
+
+        for i in 0..8 {
+            let updated_stats = record_opinion_rating(stats, ballots[i].rating);
+            let updated_window = record_opinion_rating(window, ballots[i].rating);
+            if (i as u8) < count {
+                stats = updated_stats;
+                window = updated_window;
+            }
+        }
+
+        (
+            stats_ctxt.owner.from_arcis(stats),
+            window_ctxt.owner.from_arcis(window),
+        )
+    }
+
+    /// Aggregates a batch of queued feedback responses, like `aggregate_vote_batch`
+    #[instruction]
+    pub fn aggregate_feedback_batch(
+        ballot_0: Enc<Shared, FeedbackResponse>,
+        ballot_1: Enc<Shared, FeedbackResponse>,
+        ballot_2: Enc<Shared, FeedbackResponse>,
+        ballot_3: Enc<Shared, FeedbackResponse>,
+        ballot_4: Enc<Shared, FeedbackResponse>,
+        ballot_5: Enc<Shared, FeedbackResponse>,
+        ballot_6: Enc<Shared, FeedbackResponse>,
+        ballot_7: Enc<Shared, FeedbackResponse>,
+        count: u8,
+        stats_ctxt: Enc<Mxe, FeedbackStats>,
+        window_ctxt: Enc<Mxe, FeedbackStats>,
+    ) -> (Enc<Mxe, FeedbackStats>, Enc<Mxe, FeedbackStats>) {
+        let ballots = [
+            ballot_0.to_arcis(),
+            ballot_1.to_arcis(),
+            ballot_2.to_arcis(),
+            ballot_3.to_arcis(),
+            ballot_4.to_arcis(),
+            ballot_5.to_arcis(),
+            ballot_6.to_arcis(),
+            ballot_7.to_arcis(),
+        ];
+        let mut stats = stats_ctxt.to_arcis();
+        let mut window = window_ctxt.to_arcis();
+
+        for i in 0..8 {
+            let updated_stats = record_feedback_rating(stats, ballots[i].rating);
+            let updated_window = record_feedback_rating(window, ballots[i].rating);
+            if (i as u8) < count {
+                stats = updated_stats;
+                window = updated_window;
+            }
+        }
+
+        (
+            stats_ctxt.owner.from_arcis(stats),
+            window_ctxt.owner.from_arcis(window),
+        )
+    }
 }
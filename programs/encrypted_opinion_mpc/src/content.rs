@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+/// Longest accepted content URI, e.g. `ar://<43-char id>` or `ipfs://<cid>`
+pub const MAX_CONTENT_URI_LEN: usize = 200;
+
+/// Commitment to an encrypted body that is stored off-chain.
+///
+/// The off-chain object holds only the ciphertext bytes; the nonce and auth tag stay in the
+/// account's on-chain `EncryptedBlob`, whose ciphertext is left empty. `content_hash` is the
+/// SHA-256 of the ciphertext, like `OpinionAccount.content_hash`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OffChainContent {
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub ciphertext_len: u32,
+}
+
+/// Why fetched off-chain content was rejected
+#[derive(Debug)]
+pub enum ContentError {
+    /// The store could not produce the object
+    Fetch(String),
+    /// The object's length differs from the committed `ciphertext_len`
+    LengthMismatch { expected: u32, actual: usize },
+    /// The object's hash differs from the committed `content_hash`
+    HashMismatch,
+}
+
+impl std::fmt::Display for ContentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentError::Fetch(reason) => write!(f, "failed to fetch content: {reason}"),
+            ContentError::LengthMismatch { expected, actual } => {
+                write!(f, "content is {actual} bytes, expected {expected}")
+            }
+            ContentError::HashMismatch => write!(f, "content does not match its committed hash"),
+        }
+    }
+}
+
+impl std::error::Error for ContentError {}
+
+impl OffChainContent {
+    /// Serialized size of an optional commitment
+    pub fn option_space(content: &Option<OffChainContent>) -> usize {
+        1 + content
+            .as_ref()
+            .map_or(0, |content| 32 + 4 + content.uri.len() + 4)
+    }
+
+    /// Whether the commitment is well formed enough to store
+    pub fn is_valid(&self) -> bool {
+        !self.uri.is_empty() && self.uri.len() <= MAX_CONTENT_URI_LEN && self.ciphertext_len > 0
+    }
+
+    /// Checks fetched bytes against the committed length and hash
+    pub fn verify(&self, bytes: &[u8]) -> std::result::Result<(), ContentError> {
+        if bytes.len() != self.ciphertext_len as usize {
+            return Err(ContentError::LengthMismatch {
+                expected: self.ciphertext_len,
+                actual: bytes.len(),
+            });
+        }
+        if hash(bytes).to_bytes() != self.content_hash {
+            return Err(ContentError::HashMismatch);
+        }
+        Ok(())
+    }
+
+    /// Fetches the ciphertext from `store` and returns it only if it matches the commitment
+    pub fn fetch_verified<S: ContentStore>(
+        &self,
+        store: &S,
+    ) -> std::result::Result<Vec<u8>, ContentError> {
+        let bytes = store.fetch(&self.uri).map_err(ContentError::Fetch)?;
+        self.verify(&bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// Whether an optional commitment can be stored alongside a declared on-chain `content_len`.
+///
+/// Off-chain bodies replace the on-chain ciphertext, so no upload may be declared with one.
+pub fn is_consistent(off_chain: &Option<OffChainContent>, content_len: u32) -> bool {
+    off_chain
+        .as_ref()
+        .is_none_or(|off_chain| off_chain.is_valid() && content_len == 0)
+}
+
+/// A content-addressed store that off-chain bodies can be fetched from by URI
+pub trait ContentStore {
+    fn fetch(&self, uri: &str) -> std::result::Result<Vec<u8>, String>;
+}
+
+/// A store backed by a local directory, mapping `scheme://path` to `root/path`.
+///
+/// Useful for tests and for mirrors of Arweave or IPFS content. Off-chain only: programs
+/// have no filesystem, so it is left out of the on-chain build.
+#[cfg(not(target_os = "solana"))]
+pub struct LocalFileStore {
+    root: std::path::PathBuf,
+}
+
+#[cfg(not(target_os = "solana"))]
+impl LocalFileStore {
+    pub fn new(root: impl Into<std::path::PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+impl ContentStore for LocalFileStore {
+    fn fetch(&self, uri: &str) -> std::result::Result<Vec<u8>, String> {
+        let path = uri.split_once("://").map_or(uri, |(_, path)| path);
+        let relative = std::path::Path::new(path);
+        // Only plain relative paths, so a URI cannot escape the store's root
+        if !relative
+            .components()
+            .all(|c| matches!(c, std::path::Component::Normal(_)))
+        {
+            return Err(format!("invalid content uri: {uri}"));
+        }
+        std::fs::read(self.root.join(relative)).map_err(|e| format!("{uri}: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store_with(name: &str, bytes: &[u8]) -> (LocalFileStore, std::path::PathBuf) {
+        let root = std::env::temp_dir().join(format!(
+            "opinion-content-{}-{}",
+            std::process::id(),
+            name
+        ));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join(name), bytes).unwrap();
+        (LocalFileStore::new(&root), root)
+    }
+
+    fn commitment(uri: &str, bytes: &[u8]) -> OffChainContent {
+        OffChainContent {
+            content_hash: hash(bytes).to_bytes(),
+            uri: uri.to_string(),
+            ciphertext_len: bytes.len() as u32,
+        }
+    }
+
+    #[test]
+    fn fetches_matching_content() {
+        let body = b"encrypted long-form opinion".to_vec();
+        let (store, root) = store_with("match", &body);
+
+        let fetched = commitment("ar://match", &body).fetch_verified(&store).unwrap();
+        assert_eq!(fetched, body);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn rejects_tampered_content() {
+        let body = b"encrypted long-form opinion";
+        let (store, root) = store_with("tampered", b"encrypted long-form 0pinion");
+
+        let err = commitment("ipfs://tampered", body).fetch_verified(&store).unwrap_err();
+        assert!(matches!(err, ContentError::HashMismatch));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn rejects_truncated_content() {
+        let body = b"encrypted long-form opinion";
+        let (store, root) = store_with("truncated", &body[..10]);
+
+        let err = commitment("ar://truncated", body).fetch_verified(&store).unwrap_err();
+        assert!(matches!(
+            err,
+            ContentError::LengthMismatch { expected: 27, actual: 10 }
+        ));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn rejects_missing_and_escaping_uris() {
+        let (store, root) = store_with("present", b"x");
+
+        let missing = commitment("ar://absent", b"x").fetch_verified(&store);
+        assert!(matches!(missing, Err(ContentError::Fetch(_))));
+        let escaping = commitment("ar://../present", b"x").fetch_verified(&store);
+        assert!(matches!(escaping, Err(ContentError::Fetch(_))));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
+
+/// Space taken by an `Option<AccessGate>` field: option tag, variant tag and the largest variant.
+pub const GATE_SPACE: usize = 1 + 1 + 32 + 8;
+
+/// Holding requirement for participating in a poll, survey or a post's feedback.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessGate {
+    /// Hold at least `min_amount` base units of an SPL mint
+    Token { mint: Pubkey, min_amount: u64 },
+    /// Hold an NFT whose metadata carries a verified `collection`
+    Collection { collection: Pubkey },
+}
+
+impl AccessGate {
+    /// Checks the supplied token account (and metadata, for collections) against the gate.
+    ///
+    /// The token account must be owned by `holder`. For collection gates the metadata must
+    /// belong to the token account's mint; `Account<MetadataAccount>` has already checked
+    /// that it is owned by the metadata program.
+    pub fn is_satisfied(
+        &self,
+        holder: &Pubkey,
+        token: Option<&TokenAccount>,
+        metadata: Option<&MetadataAccount>,
+    ) -> bool {
+        let Some(token) = token.filter(|token| token.owner == *holder) else {
+            return false;
+        };
+        let collection = metadata
+            .filter(|metadata| metadata.mint == token.mint)
+            .and_then(|metadata| metadata.collection.as_ref())
+            .map(|c| (c.key, c.verified));
+        self.holds(&token.mint, token.amount, collection)
+    }
+
+    /// Checks a holding of `amount` base units of `mint` against the gate.
+    ///
+    /// `collection` is the collection key and verified flag from the mint's metadata, if any.
+    fn holds(&self, mint: &Pubkey, amount: u64, collection: Option<(Pubkey, bool)>) -> bool {
+        match self {
+            AccessGate::Token {
+                mint: required,
+                min_amount,
+            } => mint == required && amount >= *min_amount,
+            AccessGate::Collection {
+                collection: required,
+            } => {
+                amount >= 1
+                    && collection.is_some_and(|(key, verified)| verified && key == *required)
+            }
+        }
+    }
+}
+
+/// Whether `holder` passes an optional gate; ungated subjects are open to everyone.
+pub fn is_satisfied(
+    gate: &Option<AccessGate>,
+    holder: &Pubkey,
+    token: Option<&TokenAccount>,
+    metadata: Option<&MetadataAccount>,
+) -> bool {
+    gate.as_ref()
+        .is_none_or(|gate| gate.is_satisfied(holder, token, metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINT: Pubkey = Pubkey::new_from_array([1; 32]);
+    const COLLECTION: Pubkey = Pubkey::new_from_array([2; 32]);
+
+    #[test]
+    fn token_gate_matches_mint_and_amount() {
+        let gate = AccessGate::Token {
+            mint: MINT,
+            min_amount: 10,
+        };
+        assert!(gate.holds(&MINT, 10, None));
+        assert!(!gate.holds(&MINT, 9, None));
+        assert!(!gate.holds(&Pubkey::new_from_array([3; 32]), 10, None));
+    }
+
+    #[test]
+    fn collection_gate_matches_verified_collection() {
+        let gate = AccessGate::Collection {
+            collection: COLLECTION,
+        };
+        assert!(gate.holds(&MINT, 1, Some((COLLECTION, true))));
+        assert!(!gate.holds(&MINT, 0, Some((COLLECTION, true))));
+        assert!(!gate.holds(&MINT, 1, Some((Pubkey::new_from_array([3; 32]), true))));
+        assert!(!gate.holds(&MINT, 1, None));
+    }
+
+    #[test]
+    fn collection_gate_rejects_unverified_collection() {
+        let gate = AccessGate::Collection {
+            collection: COLLECTION,
+        };
+        assert!(!gate.holds(&MINT, 1, Some((COLLECTION, false))));
+    }
+}
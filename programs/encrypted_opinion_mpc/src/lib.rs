@@ -1,15 +1,126 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 use arcium_anchor::prelude::*;
 
+pub mod content;
+pub mod gate;
+pub mod merkle;
+pub mod pseudonym;
+pub mod rating;
+
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
+use content::OffChainContent;
+use gate::{AccessGate, GATE_SPACE};
+use rating::RatingX10;
+
 const COMP_DEF_OFFSET_INIT_VOTE_STATS: u32 = comp_def_offset("init_vote_stats");
 const COMP_DEF_OFFSET_VOTE: u32 = comp_def_offset("vote");
 const COMP_DEF_OFFSET_REVEAL_RESULT: u32 = comp_def_offset("reveal_result");
+const COMP_DEF_OFFSET_INIT_OPTION_VOTE_STATS: u32 = comp_def_offset("init_option_vote_stats");
+const COMP_DEF_OFFSET_VOTE_OPTION: u32 = comp_def_offset("vote_option");
+const COMP_DEF_OFFSET_REVEAL_OPTION_RESULT: u32 = comp_def_offset("reveal_option_result");
 const COMP_DEF_OFFSET_INIT_OPINION_STATS: u32 = comp_def_offset("init_opinion_stats");
 const COMP_DEF_OFFSET_SUBMIT_OPINION: u32 = comp_def_offset("submit_opinion");
 const COMP_DEF_OFFSET_REVEAL_OPINION_STATS: u32 = comp_def_offset("reveal_opinion_stats");
+const COMP_DEF_OFFSET_ROTATE_OPINION_WINDOW: u32 = comp_def_offset("rotate_opinion_window");
+const COMP_DEF_OFFSET_REVEAL_OPINION_EPOCH_STATS: u32 = comp_def_offset("reveal_opinion_epoch_stats");
+const COMP_DEF_OFFSET_INIT_PULSE_STATS: u32 = comp_def_offset("init_pulse_stats");
+const COMP_DEF_OFFSET_SUBMIT_PULSE_OPINION: u32 = comp_def_offset("submit_pulse_opinion");
+const COMP_DEF_OFFSET_REVEAL_OPINION_THRESHOLD: u32 = comp_def_offset("reveal_opinion_threshold");
+const COMP_DEF_OFFSET_COMPARE_OPINION_STATS: u32 = comp_def_offset("compare_opinion_stats");
+const COMP_DEF_OFFSET_REENCRYPT_OPINION_STATS: u32 = comp_def_offset("reencrypt_opinion_stats");
+const COMP_DEF_OFFSET_COMPARE_OPINION_RESPONSE: u32 = comp_def_offset("compare_opinion_response");
+const COMP_DEF_OFFSET_SUBMIT_ELIGIBLE_OPINION: u32 = comp_def_offset("submit_eligible_opinion");
+const COMP_DEF_OFFSET_REVEAL_REJECTED_RESPONSES: u32 = comp_def_offset("reveal_rejected_responses");
 const COMP_DEF_OFFSET_INIT_FEEDBACK_STATS: u32 = comp_def_offset("init_feedback_stats");
 const COMP_DEF_OFFSET_SUBMIT_FEEDBACK: u32 = comp_def_offset("submit_feedback");
 const COMP_DEF_OFFSET_REVEAL_FEEDBACK_STATS: u32 = comp_def_offset("reveal_feedback_stats");
+const COMP_DEF_OFFSET_ROTATE_FEEDBACK_WINDOW: u32 = comp_def_offset("rotate_feedback_window");
+const COMP_DEF_OFFSET_REVEAL_FEEDBACK_EPOCH_STATS: u32 = comp_def_offset("reveal_feedback_epoch_stats");
+const COMP_DEF_OFFSET_REVEAL_FEEDBACK_THRESHOLD: u32 = comp_def_offset("reveal_feedback_threshold");
+const COMP_DEF_OFFSET_REENCRYPT_FEEDBACK_STATS: u32 = comp_def_offset("reencrypt_feedback_stats");
+const COMP_DEF_OFFSET_INIT_FORECAST_STATS: u32 = comp_def_offset("init_forecast_stats");
+const COMP_DEF_OFFSET_SUBMIT_FORECAST: u32 = comp_def_offset("submit_forecast");
+const COMP_DEF_OFFSET_REVEAL_FORECAST_MEAN: u32 = comp_def_offset("reveal_forecast_mean");
+const COMP_DEF_OFFSET_SCORE_FORECAST: u32 = comp_def_offset("score_forecast");
+const COMP_DEF_OFFSET_AGGREGATE_VOTE_BATCH: u32 = comp_def_offset("aggregate_vote_batch");
+const COMP_DEF_OFFSET_AGGREGATE_OPINION_BATCH: u32 = comp_def_offset("aggregate_opinion_batch");
+const COMP_DEF_OFFSET_AGGREGATE_FEEDBACK_BATCH: u32 = comp_def_offset("aggregate_feedback_batch");
+
+// Encrypted `VoteStats` layout inside `PollAccount`: discriminator + poll_id + nonce
+const POLL_STATS_OFFSET: u32 = 8 + 8 + 16;
+const POLL_STATS_LEN: u32 = 32 * 3;
+// Encrypted `OptionVoteStats` follow the yes/no tallies
+const POLL_OPTION_STATS_OFFSET: u32 = POLL_STATS_OFFSET + POLL_STATS_LEN + 16;
+const POLL_OPTION_STATS_LEN: u32 = 32 * (MAX_POLL_OPTIONS as u32 + 1);
+// Multi-option polls; must match the `OptionVoteStats` circuit struct
+const MAX_POLL_OPTIONS: u8 = 8;
+const NO_OPTION: u8 = u8::MAX;
+// Shared by `create_poll` and `open_runoff`
+const POLL_ACCOUNT_SPACE: usize = 8 + 8 + 16 + 32 * 3
+    + 16 + 32 * (MAX_POLL_OPTIONS as usize + 1)
+    + 4 + MAX_POLL_QUESTION_LEN + 8 + 32 + 1 + 32 + GATE_SPACE
+    + 1 + 1 + 8 + 2 + 2
+    + 1 + 1 + 8 + 1 + (1 + 32) + (1 + 2) + (1 + 32) + 1 + 1;
+
+// Encrypted `OpinionStats` layout inside `OpinionAccount`: discriminator + opinion_id + nonce
+const OPINION_STATS_OFFSET: u32 = 8 + 8 + 16;
+const OPINION_STATS_LEN: u32 = 32 * 8;
+// The current-window accumulator follows the all-time stats and its own nonce
+const OPINION_WINDOW_OFFSET: u32 = OPINION_STATS_OFFSET + OPINION_STATS_LEN + 16;
+// Encrypted `RejectedResponses` counter follows the window statistics
+const OPINION_REJECTED_OFFSET: u32 = OPINION_WINDOW_OFFSET + OPINION_STATS_LEN + 16;
+const OPINION_REJECTED_LEN: u32 = 32;
+
+// Stored `OpinionResponse` ciphertext inside `ResponseReceiptAccount`:
+// discriminator + opinion_id + respondent + pub_key + nonce
+const RESPONSE_RATING_OFFSET: u32 = 8 + 8 + 32 + 32 + 16;
+const RESPONSE_RATING_LEN: u32 = 32;
+
+// Stored `AttributeCredential` ciphertext inside `AttributeCredentialAccount`:
+// discriminator + opinion_id + respondent + pub_key + nonce
+const CREDENTIAL_ATTRIBUTE_OFFSET: u32 = 8 + 8 + 32 + 32 + 16;
+const CREDENTIAL_ATTRIBUTE_LEN: u32 = 32;
+
+// Encrypted `FeedbackStats` layout inside `FeedbackStatsAccount`: discriminator + post_id + nonce
+const FEEDBACK_STATS_OFFSET: u32 = 8 + 8 + 16;
+const FEEDBACK_STATS_LEN: u32 = 32 * 8;
+const FEEDBACK_WINDOW_OFFSET: u32 = FEEDBACK_STATS_OFFSET + FEEDBACK_STATS_LEN + 16;
+
+// Encrypted stats layout inside `StatsEpochAccount`: discriminator + epoch + nonce
+const EPOCH_STATS_OFFSET: u32 = 8 + 8 + 16;
+const EPOCH_STATS_LEN: u32 = 32 * 8;
+
+// Encrypted `ForecastStats` layout inside `ForecastAccount`: discriminator + forecast_id + nonce
+const FORECAST_STATS_OFFSET: u32 = 8 + 8 + 16;
+const FORECAST_STATS_LEN: u32 = 32 * 2;
+const MAX_FORECAST_QUESTION_LEN: usize = 200;
+const MAX_OPINION_TITLE_LEN: usize = 100;
+const MAX_POLL_QUESTION_LEN: usize = 200;
+// Ciphertext limits for posts and comments; bodies beyond one transaction are appended
+const MAX_POST_TITLE_LEN: usize = 256;
+const MAX_POST_TOPIC_LEN: usize = 64;
+const MAX_POST_CONTENT_LEN: usize = 10_000;
+const MAX_COMMENT_CONTENT_LEN: usize = 2_000;
+// Deepest reply nesting; top-level comments have depth 0
+const MAX_COMMENT_DEPTH: u16 = 16;
+// Topic names and descriptions are public plaintext
+const MAX_TOPIC_NAME_LEN: usize = 50;
+const MAX_TOPIC_DESCRIPTION_LEN: usize = 280;
+const MAX_TOPIC_MODERATORS: usize = 10;
+const FORECAST_LEADERBOARD_SIZE: usize = 10;
+// Ballots aggregated per batched computation; must match the `aggregate_*_batch` circuits
+const BALLOT_BATCH_SIZE: usize = 8;
+// Ballots a queue can hold while batches are aggregated from its front
+const BALLOT_QUEUE_CAPACITY: usize = BALLOT_BATCH_SIZE * 4;
+// Shared by the `enqueue_*` instructions
+const BALLOT_QUEUE_SPACE: usize = 8 + 32 + 1 + 1 + 1 + (32 + 16 + 32) * BALLOT_QUEUE_CAPACITY;
+
+// Rating thresholds are scaled by 10 to match `average_rating` (35 = 3.5)
+const MIN_RATING_THRESHOLD: u8 = 10;
+const MAX_RATING_THRESHOLD: u8 = 50;
+const MAX_COMPARISON_MARGIN: u8 = 40;
 
 declare_id!("AqQHGMDSDezFmf348JaymgPpLSfzswef9EXd1Hw5PUoM");
 
@@ -33,6 +144,21 @@ pub mod encrypted_opinion_mpc {
         Ok(())
     }
 
+    pub fn init_init_option_vote_stats_comp_def(ctx: Context<InitInitOptionVoteStatsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_vote_option_comp_def(ctx: Context<InitVoteOptionCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reveal_option_result_comp_def(ctx: Context<InitRevealOptionResultCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
     pub fn init_init_opinion_stats_comp_def(ctx: Context<InitInitOpinionStatsCompDef>) -> Result<()> {
         init_comp_def(ctx.accounts, true, 0, None, None)?;
         Ok(())
@@ -48,6 +174,56 @@ pub mod encrypted_opinion_mpc {
         Ok(())
     }
 
+    pub fn init_rotate_opinion_window_comp_def(ctx: Context<InitRotateOpinionWindowCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reveal_opinion_epoch_stats_comp_def(ctx: Context<InitRevealOpinionEpochStatsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_init_pulse_stats_comp_def(ctx: Context<InitInitPulseStatsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_submit_pulse_opinion_comp_def(ctx: Context<InitSubmitPulseOpinionCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reveal_opinion_threshold_comp_def(ctx: Context<InitRevealOpinionThresholdCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_compare_opinion_stats_comp_def(ctx: Context<InitCompareOpinionStatsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reencrypt_opinion_stats_comp_def(ctx: Context<InitReencryptOpinionStatsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_compare_opinion_response_comp_def(ctx: Context<InitCompareOpinionResponseCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_submit_eligible_opinion_comp_def(ctx: Context<InitSubmitEligibleOpinionCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reveal_rejected_responses_comp_def(ctx: Context<InitRevealRejectedResponsesCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
     pub fn init_init_feedback_stats_comp_def(ctx: Context<InitInitFeedbackStatsCompDef>) -> Result<()> {
         init_comp_def(ctx.accounts, true, 0, None, None)?;
         Ok(())
@@ -63,21 +239,157 @@ pub mod encrypted_opinion_mpc {
         Ok(())
     }
 
+    pub fn init_rotate_feedback_window_comp_def(ctx: Context<InitRotateFeedbackWindowCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reveal_feedback_epoch_stats_comp_def(ctx: Context<InitRevealFeedbackEpochStatsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reveal_feedback_threshold_comp_def(ctx: Context<InitRevealFeedbackThresholdCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reencrypt_feedback_stats_comp_def(ctx: Context<InitReencryptFeedbackStatsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_init_forecast_stats_comp_def(ctx: Context<InitInitForecastStatsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_submit_forecast_comp_def(ctx: Context<InitSubmitForecastCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reveal_forecast_mean_comp_def(ctx: Context<InitRevealForecastMeanCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_score_forecast_comp_def(ctx: Context<InitScoreForecastCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_aggregate_vote_batch_comp_def(ctx: Context<InitAggregateVoteBatchCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_aggregate_opinion_batch_comp_def(ctx: Context<InitAggregateOpinionBatchCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_aggregate_feedback_batch_comp_def(ctx: Context<InitAggregateFeedbackBatchCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, 0, None, None)?;
+        Ok(())
+    }
+
+    // Create the registry that hands out post, poll, survey, topic and forecast ids; called
+    // once per deployment
+    pub fn init_registry(ctx: Context<InitRegistry>) -> Result<()> {
+        let registry = &mut ctx.accounts.registry_account;
+        registry.next_post_id = 0;
+        registry.next_poll_id = 0;
+        registry.next_opinion_id = 0;
+        registry.next_topic_id = 0;
+        registry.next_forecast_id = 0;
+        Ok(())
+    }
+
+    // Create a poll, optionally restricted to an allowlist of submitters or to token holders.
+    // `option_count` of 0 makes a yes/no poll; 2 or more makes a multi-option poll, which
+    // can hold a runoff between the two leaders when no option wins a majority. A non-zero
+    // `deadline` closes voting at that time. The poll id is assigned by the registry.
+    pub fn create_poll(
+        ctx: Context<CreatePoll>,
+        question: String,
+        eligible_root: Option<[u8; 32]>,
+        gate: Option<AccessGate>,
+        quorum: Quorum,
+        pass_threshold: PassThreshold,
+        option_count: u8,
+        runoff_on_no_majority: bool,
+        deadline: i64,
+    ) -> Result<()> {
+        let now = ctx.accounts.clock.unix_timestamp;
+        require!(question.len() <= MAX_POLL_QUESTION_LEN, ErrorCode::QuestionTooLong);
+        require!(quorum.required_ballots().is_some(), ErrorCode::InvalidQuorum);
+        require!(pass_threshold.is_valid(), ErrorCode::InvalidPassThreshold);
+        require!(
+            option_count == 0 || (2..=MAX_POLL_OPTIONS).contains(&option_count),
+            ErrorCode::InvalidOptionCount
+        );
+        require!(
+            option_count > 0 || !runoff_on_no_majority,
+            ErrorCode::InvalidOptionCount
+        );
+        require!(deadline == 0 || deadline > now, ErrorCode::InvalidDeadline);
+
+        let poll_id = ctx.accounts.registry_account.assign_poll_id();
+        let poll = &mut ctx.accounts.poll_account;
+        poll.poll_id = poll_id;
+        poll.question = question;
+        poll.created_at = now;
+        poll.authority = ctx.accounts.authority.key();
+        poll.eligible_root = eligible_root;
+        poll.gate = gate;
+        poll.quorum = quorum;
+        poll.pass_threshold = pass_threshold;
+        poll.option_count = option_count;
+        poll.runoff_on_no_majority = runoff_on_no_majority;
+        poll.deadline = deadline;
+        poll.round = 0;
+        poll.parent_poll = None;
+        poll.runoff_candidates = None;
+        poll.runoff_poll = None;
+        poll.stats_initialized = false;
+        poll.result_revealed = false;
+
+        emit!(PollCreatedEvent {
+            poll_id,
+            authority: poll.authority,
+            allowlisted: eligible_root.is_some(),
+            quorum,
+            pass_threshold,
+            option_count,
+        });
+
+        Ok(())
+    }
+
     // Initialize vote statistics
     pub fn init_vote_stats(
         ctx: Context<InitVoteStats>,
+        computation_offset: u64,
         _poll_id: u64,
     ) -> Result<()> {
+        let poll = &mut ctx.accounts.poll_account;
+        require!(!poll.is_multi_option(), ErrorCode::WrongPollKind);
+        require!(!poll.stats_initialized, ErrorCode::StatsAlreadyInitialized);
+        poll.stats_initialized = true;
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
         let args = vec![];
 
         queue_computation(
             ctx.accounts,
-            0, // computation_offset
+            computation_offset,
             args,
             None,
-            vec![InitVoteStatsCallback::callback_ix(&[])],
+            vec![InitVoteStatsCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_account.key(),
+                is_writable: true,
+            }])],
         )?;
 
         Ok(())
@@ -91,13 +403,36 @@ pub mod encrypted_opinion_mpc {
         ciphertext_vote: [u8; 32],
         pub_key: [u8; 32],
         nonce: u128,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
+        require!(
+            !ctx.accounts.poll_account.is_multi_option(),
+            ErrorCode::WrongPollKind
+        );
+        require!(
+            ctx.accounts.poll_account.is_open(Clock::get()?.unix_timestamp),
+            ErrorCode::DeadlinePassed
+        );
+        require!(
+            merkle::is_allowlisted(
+                ctx.accounts.poll_account.eligible_root,
+                &ctx.accounts.submitter.key(),
+                &proof,
+            ),
+            ErrorCode::NotAllowlisted
+        );
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
         let args = vec![
             Argument::ArcisPubkey(pub_key),
             Argument::PlaintextU128(nonce),
             Argument::EncryptedBool(ciphertext_vote),
+            Argument::PlaintextU128(ctx.accounts.poll_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.poll_account.key(),
+                POLL_STATS_OFFSET,
+                POLL_STATS_LEN,
+            ),
         ];
 
         queue_computation(
@@ -105,7 +440,16 @@ pub mod encrypted_opinion_mpc {
             computation_offset,
             args,
             None,
-            vec![VoteCallback::callback_ix(&[])],
+            vec![VoteCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.poll_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.submitter.key(),
+                    is_writable: false,
+                },
+            ])],
         )?;
 
         Ok(())
@@ -117,56 +461,107 @@ pub mod encrypted_opinion_mpc {
         computation_offset: u64,
         _poll_id: u64,
     ) -> Result<()> {
+        let poll = &mut ctx.accounts.poll_account;
+        require!(!poll.is_multi_option(), ErrorCode::WrongPollKind);
+        require!(
+            poll.deadline == 0 || Clock::get()?.unix_timestamp >= poll.deadline,
+            ErrorCode::DeadlineNotReached
+        );
+        require!(!poll.result_revealed, ErrorCode::ResultAlreadyRevealed);
+        poll.result_revealed = true;
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-        let args = vec![];
+        let min_ballots = ctx
+            .accounts
+            .poll_account
+            .quorum
+            .required_ballots()
+            .ok_or(ErrorCode::InvalidQuorum)?;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.poll_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.poll_account.key(),
+                POLL_STATS_OFFSET,
+                POLL_STATS_LEN,
+            ),
+            Argument::PlaintextU64(min_ballots),
+            Argument::PlaintextU16(ctx.accounts.poll_account.pass_threshold.numerator),
+            Argument::PlaintextU16(ctx.accounts.poll_account.pass_threshold.denominator),
+        ];
 
         queue_computation(
             ctx.accounts,
             computation_offset,
             args,
             None,
-            vec![RevealResultCallback::callback_ix(&[])],
+            vec![RevealResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_account.key(),
+                is_writable: true,
+            }])],
         )?;
 
         Ok(())
     }
 
-    // Initialize opinion statistics
-    pub fn init_opinion_stats(
-        ctx: Context<InitOpinionStats>,
-        _opinion_id: u64,
+    // Initialize the per-option tallies of a multi-option poll or runoff
+    pub fn init_option_vote_stats(
+        ctx: Context<InitOptionVoteStats>,
+        computation_offset: u64,
+        _poll_id: u64,
     ) -> Result<()> {
+        let poll = &mut ctx.accounts.poll_account;
+        require!(poll.is_multi_option(), ErrorCode::WrongPollKind);
+        require!(!poll.stats_initialized, ErrorCode::StatsAlreadyInitialized);
+        poll.stats_initialized = true;
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
         let args = vec![];
 
         queue_computation(
             ctx.accounts,
-            0, // computation_offset
+            computation_offset,
             args,
             None,
-            vec![InitOpinionStatsCallback::callback_ix(&[])],
+            vec![InitOptionVoteStatsCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_account.key(),
+                is_writable: true,
+            }])],
         )?;
 
         Ok(())
     }
 
-    // Submit opinion response
-    pub fn submit_opinion_response(
-        ctx: Context<SubmitOpinionResponse>,
+    // Submit an encrypted vote for one option of a multi-option poll
+    pub fn vote_option(
+        ctx: Context<VoteOption>,
         computation_offset: u64,
-        _opinion_id: u64,
-        ciphertext_rating: [u8; 32],
+        _poll_id: u64,
+        ciphertext_option: [u8; 32],
         pub_key: [u8; 32],
         nonce: u128,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
+        let poll = &ctx.accounts.poll_account;
+        require!(poll.is_multi_option(), ErrorCode::WrongPollKind);
+        require!(poll.is_open(Clock::get()?.unix_timestamp), ErrorCode::DeadlinePassed);
+        require!(
+            merkle::is_allowlisted(poll.eligible_root, &ctx.accounts.submitter.key(), &proof),
+            ErrorCode::NotAllowlisted
+        );
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
         let args = vec![
             Argument::ArcisPubkey(pub_key),
             Argument::PlaintextU128(nonce),
-            Argument::EncryptedU8(ciphertext_rating),
+            Argument::EncryptedU8(ciphertext_option),
+            Argument::PlaintextU8(ctx.accounts.poll_account.option_count),
+            Argument::PlaintextU128(ctx.accounts.poll_account.option_stats_nonce),
+            Argument::Account(
+                ctx.accounts.poll_account.key(),
+                POLL_OPTION_STATS_OFFSET,
+                POLL_OPTION_STATS_LEN,
+            ),
         ];
 
         queue_computation(
@@ -174,120 +569,247 @@ pub mod encrypted_opinion_mpc {
             computation_offset,
             args,
             None,
-            vec![SubmitOpinionCallback::callback_ix(&[])],
+            vec![VoteOptionCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.poll_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.submitter.key(),
+                    is_writable: false,
+                },
+            ])],
         )?;
 
         Ok(())
     }
 
-    // Reveal opinion statistics
-    pub fn reveal_opinion_stats(
-        ctx: Context<RevealOpinionStats>,
+    // Reveal the winner of a multi-option poll, or the two leaders if a runoff is needed
+    pub fn reveal_option_result(
+        ctx: Context<RevealOptionResult>,
         computation_offset: u64,
-        _opinion_id: u64,
+        _poll_id: u64,
     ) -> Result<()> {
+        let poll = &ctx.accounts.poll_account;
+        require!(poll.is_multi_option(), ErrorCode::WrongPollKind);
+        require!(
+            poll.deadline == 0 || Clock::get()?.unix_timestamp >= poll.deadline,
+            ErrorCode::DeadlineNotReached
+        );
+        require!(poll.runoff_poll.is_none(), ErrorCode::RunoffAlreadyOpened);
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-        let args = vec![];
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.poll_account.option_stats_nonce),
+            Argument::Account(
+                ctx.accounts.poll_account.key(),
+                POLL_OPTION_STATS_OFFSET,
+                POLL_OPTION_STATS_LEN,
+            ),
+            Argument::PlaintextU8(ctx.accounts.poll_account.option_count),
+            Argument::PlaintextBool(ctx.accounts.poll_account.runoff_on_no_majority),
+        ];
 
         queue_computation(
             ctx.accounts,
             computation_offset,
             args,
             None,
-            vec![RevealOpinionStatsCallback::callback_ix(&[])],
+            vec![RevealOptionResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_account.key(),
+                is_writable: true,
+            }])],
         )?;
 
         Ok(())
     }
 
-    // Create a new opinion post
-    pub fn create_opinion_post(
-        ctx: Context<CreateOpinionPost>,
-        post_id: u64,
-        encrypted_title: [u8; 32],
-        encrypted_content: [u8; 128],
-        encrypted_topic: [u8; 16],
+    // Open the runoff between the two leading options of a poll that had no majority.
+    // The runoff is a two-option poll whose option 0 and 1 are the parent's
+    // `runoff_candidates`; it needs its own `init_option_vote_stats` before voting.
+    pub fn open_runoff(
+        ctx: Context<OpenRunoff>,
+        deadline: i64,
     ) -> Result<()> {
-        let post = &mut ctx.accounts.post_account;
-        post.post_id = post_id;
-        post.encrypted_title = encrypted_title;
-        post.encrypted_content = encrypted_content;
-        post.encrypted_topic = encrypted_topic;
-        post.author = ctx.accounts.author.key();
-        post.created_at = ctx.accounts.clock.unix_timestamp;
-        post.total_comments = 0;
-        post.total_feedback = 0;
+        let now = ctx.accounts.clock.unix_timestamp;
+        require!(deadline == 0 || deadline > now, ErrorCode::InvalidDeadline);
+        let runoff_poll_id = ctx.accounts.registry_account.assign_poll_id();
 
-        emit!(OpinionPostCreatedEvent {
-            post_id,
-            author: ctx.accounts.author.key(),
+        let parent_key = ctx.accounts.poll_account.key();
+        let runoff_key = ctx.accounts.runoff_poll_account.key();
+        let parent = &mut ctx.accounts.poll_account;
+        require!(parent.runoff_poll.is_none(), ErrorCode::RunoffAlreadyOpened);
+        let candidates = parent.runoff_candidates.ok_or(ErrorCode::NoRunoffPending)?;
+        parent.runoff_poll = Some(runoff_key);
+
+        let runoff = &mut ctx.accounts.runoff_poll_account;
+        runoff.poll_id = runoff_poll_id;
+        runoff.question = parent.question.clone();
+        runoff.created_at = now;
+        runoff.authority = parent.authority;
+        runoff.eligible_root = parent.eligible_root;
+        runoff.gate = parent.gate;
+        runoff.quorum = parent.quorum;
+        runoff.pass_threshold = parent.pass_threshold;
+        runoff.option_count = 2;
+        runoff.runoff_on_no_majority = false;
+        runoff.deadline = deadline;
+        runoff.round = parent.round.checked_add(1).ok_or(ErrorCode::InvalidRunoff)?;
+        runoff.parent_poll = Some(parent_key);
+        runoff.runoff_candidates = None;
+        runoff.runoff_poll = None;
+        runoff.stats_initialized = false;
+        runoff.result_revealed = false;
+
+        emit!(RunoffOpenedEvent {
+            poll_id: parent.poll_id,
+            runoff_poll_id,
+            round: runoff.round,
+            candidates,
         });
 
         Ok(())
     }
 
-    // Add a comment to an opinion post
-    pub fn add_comment(
-        ctx: Context<AddComment>,
-        comment_id: u64,
-        encrypted_content: [u8; 64],
+    // Create an opinion survey, optionally recurring every `period` seconds until the deadline.
+    // A non-zero `eligible_attributes` bitmask restricts responses to respondents whose
+    // encrypted attribute is in the set; see `submit_eligible_opinion_response`. An
+    // `eligible_root` restricts submitters to the members of a Merkle allowlist, and a
+    // `gate` to holders of a token or collection NFT. The survey id is assigned by the registry.
+    // A `rating_threshold` (x10, 10..=50) is the only threshold the authority can later reveal
+    // the average against, so the average cannot be narrowed down by repeated reveals.
+    pub fn create_opinion(
+        ctx: Context<CreateOpinion>,
+        title: String,
+        content_hash: [u8; 32],
+        deadline: i64,
+        period: i64,
+        eligible_attributes: u64,
+        eligible_root: Option<[u8; 32]>,
+        gate: Option<AccessGate>,
+        rating_threshold: Option<u8>,
     ) -> Result<()> {
-        let comment = &mut ctx.accounts.comment_account;
-        comment.comment_id = comment_id;
-        comment.post_id = ctx.accounts.post_account.post_id;
-        comment.encrypted_content = encrypted_content;
-        comment.author = ctx.accounts.author.key();
-        comment.created_at = ctx.accounts.clock.unix_timestamp;
+        let now = ctx.accounts.clock.unix_timestamp;
+        require!(title.len() <= MAX_OPINION_TITLE_LEN, ErrorCode::TitleTooLong);
+        require!(deadline > now, ErrorCode::InvalidDeadline);
+        require!(period >= 0, ErrorCode::InvalidPeriod);
+        require!(
+            rating_threshold.is_none_or(|threshold| {
+                (MIN_RATING_THRESHOLD..=MAX_RATING_THRESHOLD).contains(&threshold)
+            }),
+            ErrorCode::InvalidThreshold
+        );
 
-        // Increment comment count on post
-        let post = &mut ctx.accounts.post_account;
-        post.total_comments += 1;
+        let opinion_id = ctx.accounts.registry_account.assign_opinion_id();
+        let opinion = &mut ctx.accounts.opinion_account;
+        opinion.opinion_id = opinion_id;
+        opinion.current_epoch = 0;
+        opinion.window_started_at = now;
+        opinion.title = title;
+        opinion.content_hash = content_hash;
+        opinion.created_at = now;
+        opinion.deadline = deadline;
+        opinion.total_responses = 0;
+        opinion.authority = ctx.accounts.authority.key();
+        opinion.period = period;
+        opinion.eligible_attributes = eligible_attributes;
+        opinion.eligible_root = eligible_root;
+        opinion.gate = gate;
+        opinion.rating_threshold = rating_threshold;
+        opinion.threshold_revealed = false;
+        opinion.stats_initialized = false;
 
-        emit!(CommentAddedEvent {
-            comment_id,
-            post_id: post.post_id,
-            author: ctx.accounts.author.key(),
+        emit!(OpinionCreatedEvent {
+            opinion_id,
+            authority: opinion.authority,
+            deadline,
+            period,
+            eligible_attributes,
+            allowlisted: eligible_root.is_some(),
         });
 
         Ok(())
     }
 
-    // Initialize feedback statistics for a post
-    pub fn init_feedback_stats(
-        ctx: Context<InitFeedbackStats>,
-        _post_id: u64,
+    // Initialize opinion statistics
+    pub fn init_opinion_stats(
+        ctx: Context<InitOpinionStats>,
+        computation_offset: u64,
+        _opinion_id: u64,
     ) -> Result<()> {
+        let opinion = &mut ctx.accounts.opinion_account;
+        require!(!opinion.stats_initialized, ErrorCode::StatsAlreadyInitialized);
+        opinion.stats_initialized = true;
+        opinion.window_started_at = Clock::get()?.unix_timestamp;
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
         let args = vec![];
 
         queue_computation(
             ctx.accounts,
-            0, // computation_offset
+            computation_offset,
             args,
             None,
-            vec![InitFeedbackStatsCallback::callback_ix(&[])],
+            vec![InitOpinionStatsCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.opinion_account.key(),
+                is_writable: true,
+            }])],
         )?;
 
         Ok(())
     }
 
-    // Submit feedback response for a post
-    pub fn submit_feedback_response(
-        ctx: Context<SubmitFeedbackResponse>,
+    // Submit opinion response. The ciphertext is also kept in a per-respondent receipt, which
+    // limits each wallet to one direct response and backs `compare_opinion_response`.
+    pub fn submit_opinion_response(
+        ctx: Context<SubmitOpinionResponse>,
         computation_offset: u64,
-        _post_id: u64,
+        _opinion_id: u64,
         ciphertext_rating: [u8; 32],
         pub_key: [u8; 32],
         nonce: u128,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
+        let opinion = &ctx.accounts.opinion_account;
+        require!(!opinion.is_recurring(), ErrorCode::PulseResponseRequired);
+        require!(opinion.is_open(Clock::get()?.unix_timestamp), ErrorCode::DeadlinePassed);
+        require!(
+            merkle::is_allowlisted(
+                ctx.accounts.opinion_account.eligible_root,
+                &ctx.accounts.submitter.key(),
+                &proof,
+            ),
+            ErrorCode::NotAllowlisted
+        );
+        require!(
+            !ctx.accounts.opinion_account.has_eligibility_rule(),
+            ErrorCode::EligibilityCheckRequired
+        );
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
+        let receipt = &mut ctx.accounts.response_account;
+        receipt.opinion_id = ctx.accounts.opinion_account.opinion_id;
+        receipt.respondent = ctx.accounts.submitter.key();
+        receipt.pub_key = pub_key;
+        receipt.nonce = nonce;
+        receipt.encrypted_rating = ciphertext_rating;
+
         let args = vec![
             Argument::ArcisPubkey(pub_key),
             Argument::PlaintextU128(nonce),
             Argument::EncryptedU8(ciphertext_rating),
+            Argument::PlaintextU128(ctx.accounts.opinion_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_STATS_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+            Argument::PlaintextU128(ctx.accounts.opinion_account.window_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_WINDOW_OFFSET,
+                OPINION_STATS_LEN,
+            ),
         ];
 
         queue_computation(
@@ -295,297 +817,5898 @@ pub mod encrypted_opinion_mpc {
             computation_offset,
             args,
             None,
-            vec![SubmitFeedbackCallback::callback_ix(&[])],
+            vec![SubmitOpinionCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.submitter.key(),
+                    is_writable: false,
+                },
+            ])],
         )?;
 
         Ok(())
     }
 
-    // Reveal feedback statistics for a post
-    pub fn reveal_feedback_stats(
-        ctx: Context<RevealFeedbackStats>,
+    // Issue a respondent's eligibility attribute for a survey with an eligibility rule.
+    // The authority encrypts the attribute under its own key for the MXE, so the
+    // respondent can use the credential but cannot choose or read the attribute.
+    pub fn issue_attribute_credential(
+        ctx: Context<IssueAttributeCredential>,
+        _opinion_id: u64,
+        respondent: Pubkey,
+        ciphertext_attribute: [u8; 32],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.opinion_account.has_eligibility_rule(),
+            ErrorCode::NoEligibilityRule
+        );
+
+        let credential = &mut ctx.accounts.credential_account;
+        credential.opinion_id = ctx.accounts.opinion_account.opinion_id;
+        credential.respondent = respondent;
+        credential.pub_key = pub_key;
+        credential.nonce = nonce;
+        credential.encrypted_attribute = ciphertext_attribute;
+
+        emit!(AttributeCredentialIssuedEvent {
+            opinion_id: credential.opinion_id,
+            respondent,
+        });
+
+        Ok(())
+    }
+
+    // Submit an opinion response for a survey with an eligibility rule. The attribute is
+    // read from the credential the authority issued to the submitter and checked against
+    // the survey's predicate inside MPC; ineligible responses are not counted and only
+    // increment an encrypted rejected counter.
+    pub fn submit_eligible_opinion_response(
+        ctx: Context<SubmitEligibleOpinionResponse>,
         computation_offset: u64,
-        _post_id: u64,
+        _opinion_id: u64,
+        ciphertext_rating: [u8; 32],
+        pub_key: [u8; 32],
+        nonce: u128,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
+        let opinion = &ctx.accounts.opinion_account;
+        require!(!opinion.is_recurring(), ErrorCode::PulseResponseRequired);
+        require!(opinion.is_open(Clock::get()?.unix_timestamp), ErrorCode::DeadlinePassed);
+        require!(
+            merkle::is_allowlisted(
+                ctx.accounts.opinion_account.eligible_root,
+                &ctx.accounts.submitter.key(),
+                &proof,
+            ),
+            ErrorCode::NotAllowlisted
+        );
+        require!(
+            ctx.accounts.opinion_account.has_eligibility_rule(),
+            ErrorCode::NoEligibilityRule
+        );
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-        let args = vec![];
+        let args = vec![
+            Argument::ArcisPubkey(pub_key),
+            Argument::PlaintextU128(nonce),
+            Argument::EncryptedU8(ciphertext_rating),
+            Argument::ArcisPubkey(ctx.accounts.credential_account.pub_key),
+            Argument::PlaintextU128(ctx.accounts.credential_account.nonce),
+            Argument::Account(
+                ctx.accounts.credential_account.key(),
+                CREDENTIAL_ATTRIBUTE_OFFSET,
+                CREDENTIAL_ATTRIBUTE_LEN,
+            ),
+            Argument::PlaintextU64(ctx.accounts.opinion_account.eligible_attributes),
+            Argument::PlaintextU128(ctx.accounts.opinion_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_STATS_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+            Argument::PlaintextU128(ctx.accounts.opinion_account.window_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_WINDOW_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+            Argument::PlaintextU128(ctx.accounts.opinion_account.rejected_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_REJECTED_OFFSET,
+                OPINION_REJECTED_LEN,
+            ),
+        ];
 
         queue_computation(
             ctx.accounts,
             computation_offset,
             args,
             None,
-            vec![RevealFeedbackStatsCallback::callback_ix(&[])],
+            vec![SubmitEligibleOpinionCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.submitter.key(),
+                    is_writable: false,
+                },
+            ])],
         )?;
 
         Ok(())
     }
 
-    // Callbacks for MPC computation results
-    #[arcium_callback(encrypted_ix = "init_vote_stats")]
-    pub fn init_vote_stats_callback(
-        ctx: Context<InitVoteStatsCallback>,
-        output: ComputationOutputs<InitVoteStatsOutput>,
+    // Reveal how many responses failed the eligibility check
+    pub fn reveal_rejected_responses(
+        ctx: Context<RevealRejectedResponses>,
+        computation_offset: u64,
+        _opinion_id: u64,
     ) -> Result<()> {
-        let _stats = match output {
-            ComputationOutputs::Success(InitVoteStatsOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        // Vote stats initialized successfully
-        Ok(())
-    }
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-    #[arcium_callback(encrypted_ix = "vote")]
-    pub fn vote_callback(
-        ctx: Context<VoteCallback>,
-        output: ComputationOutputs<VoteOutput>,
-    ) -> Result<()> {
-        let _updated_stats = match output {
-            ComputationOutputs::Success(VoteOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.opinion_account.rejected_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_REJECTED_OFFSET,
+                OPINION_REJECTED_LEN,
+            ),
+        ];
 
-        emit!(VoteSubmittedEvent {
-            poll_id: ctx.accounts.poll_account.poll_id,
-            submitter: ctx.accounts.submitter.key(),
-        });
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealRejectedResponsesCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.opinion_account.key(),
+                is_writable: false,
+            }])],
+        )?;
 
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "reveal_result")]
-    pub fn reveal_result_callback(
-        ctx: Context<RevealResultCallback>,
-        output: ComputationOutputs<RevealResultOutput>,
+    // Reveal opinion statistics publicly. Only the survey authority can do this, so stats
+    // kept private with `reencrypt_opinion_stats` stay private.
+    pub fn reveal_opinion_stats(
+        ctx: Context<RevealOpinionStats>,
+        computation_offset: u64,
+        _opinion_id: u64,
     ) -> Result<()> {
-        let result = match output {
-            ComputationOutputs::Success(RevealResultOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-        emit!(PollResultRevealedEvent {
-            poll_id: ctx.accounts.poll_account.poll_id,
-            majority_yes: result,
-        });
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.opinion_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_STATS_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealOpinionStatsCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.opinion_account.key(),
+                is_writable: false,
+            }])],
+        )?;
 
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "init_opinion_stats")]
-    pub fn init_opinion_stats_callback(
-        ctx: Context<InitOpinionStatsCallback>,
-        output: ComputationOutputs<InitOpinionStatsOutput>,
+    // Snapshot the current opinion window into an epoch account and start a new window
+    pub fn rotate_opinion_window(
+        ctx: Context<RotateOpinionWindow>,
+        computation_offset: u64,
+        _opinion_id: u64,
     ) -> Result<()> {
-        let _stats = match output {
-            ComputationOutputs::Success(InitOpinionStatsOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+        require!(
+            !ctx.accounts.opinion_account.is_recurring(),
+            ErrorCode::RecurringSurvey
+        );
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let epoch = &mut ctx.accounts.epoch_account;
+        epoch.epoch = ctx.accounts.opinion_account.current_epoch;
+        epoch.subject = ctx.accounts.opinion_account.key();
+        epoch.started_at = ctx.accounts.opinion_account.window_started_at;
+        epoch.ended_at = Clock::get()?.unix_timestamp;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.opinion_account.window_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_WINDOW_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RotateOpinionWindowCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.epoch_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
 
-        // Opinion stats initialized successfully
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "submit_opinion")]
-    pub fn submit_opinion_callback(
-        ctx: Context<SubmitOpinionCallback>,
-        output: ComputationOutputs<SubmitOpinionOutput>,
+    // Reveal the opinion statistics captured for one epoch
+    pub fn reveal_opinion_epoch_stats(
+        ctx: Context<RevealOpinionEpochStats>,
+        computation_offset: u64,
+        _opinion_id: u64,
+        _epoch: u64,
     ) -> Result<()> {
-        let _updated_stats = match output {
-            ComputationOutputs::Success(SubmitOpinionOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+        // Periods of a recurring survey can only be revealed once they have closed
+        if ctx.accounts.opinion_account.is_recurring() {
+            require!(
+                Clock::get()?.unix_timestamp >= ctx.accounts.epoch_account.ended_at,
+                ErrorCode::DeadlineNotReached
+            );
+        }
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-        emit!(OpinionSubmittedEvent {
-            opinion_id: ctx.accounts.opinion_account.opinion_id,
-            submitter: ctx.accounts.submitter.key(),
-        });
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.epoch_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.epoch_account.key(),
+                EPOCH_STATS_OFFSET,
+                EPOCH_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealOpinionEpochStatsCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.epoch_account.key(),
+                    is_writable: false,
+                },
+            ])],
+        )?;
 
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "reveal_opinion_stats")]
-    pub fn reveal_opinion_stats_callback(
-        ctx: Context<RevealOpinionStatsCallback>,
-        output: ComputationOutputs<RevealOpinionStatsOutput>,
+    // Initialize the encrypted statistics for one period of a recurring survey
+    pub fn init_pulse_stats(
+        ctx: Context<InitPulseStats>,
+        computation_offset: u64,
+        _opinion_id: u64,
+        epoch: u64,
     ) -> Result<()> {
-        let stats = match output {
-            ComputationOutputs::Success(RevealOpinionStatsOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+        let (started_at, ended_at) = ctx
+            .accounts
+            .opinion_account
+            .epoch_bounds(epoch)
+            .ok_or(ErrorCode::InvalidEpoch)?;
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-        emit!(OpinionStatsRevealedEvent {
-            opinion_id: ctx.accounts.opinion_account.opinion_id,
-            total_responses: stats.field_0,
-            average_rating: ((stats.field_1 * 10) / stats.field_0.max(1)) as u8,
-            rating_distribution: stats.field_2,
-        });
+        let period = &mut ctx.accounts.epoch_account;
+        require!(!period.stats_initialized, ErrorCode::StatsAlreadyInitialized);
+        period.stats_initialized = true;
+        period.epoch = epoch;
+        period.subject = ctx.accounts.opinion_account.key();
+        period.started_at = started_at;
+        period.ended_at = ended_at;
+
+        let args = vec![];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitPulseStatsCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.epoch_account.key(),
+                is_writable: true,
+            }])],
+        )?;
 
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "init_feedback_stats")]
-    pub fn init_feedback_stats_callback(
-        ctx: Context<InitFeedbackStatsCallback>,
-        output: ComputationOutputs<InitFeedbackStatsOutput>,
+    // Submit a response to the current period of a recurring survey
+    pub fn submit_pulse_response(
+        ctx: Context<SubmitPulseResponse>,
+        computation_offset: u64,
+        _opinion_id: u64,
+        ciphertext_rating: [u8; 32],
+        pub_key: [u8; 32],
+        nonce: u128,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
-        let _stats = match output {
-            ComputationOutputs::Success(InitFeedbackStatsOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+        require!(
+            merkle::is_allowlisted(
+                ctx.accounts.opinion_account.eligible_root,
+                &ctx.accounts.submitter.key(),
+                &proof,
+            ),
+            ErrorCode::NotAllowlisted
+        );
+        require!(
+            !ctx.accounts.opinion_account.has_eligibility_rule(),
+            ErrorCode::EligibilityCheckRequired
+        );
+        let current_epoch = ctx
+            .accounts
+            .opinion_account
+            .epoch_at(Clock::get()?.unix_timestamp)
+            .ok_or(ErrorCode::InvalidEpoch)?;
+        require!(
+            ctx.accounts.epoch_account.epoch == current_epoch,
+            ErrorCode::InvalidEpoch
+        );
+        require!(
+            ctx.accounts.epoch_account.stats_initialized,
+            ErrorCode::StatsNotInitialized
+        );
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let args = vec![
+            Argument::ArcisPubkey(pub_key),
+            Argument::PlaintextU128(nonce),
+            Argument::EncryptedU8(ciphertext_rating),
+            Argument::PlaintextU128(ctx.accounts.epoch_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.epoch_account.key(),
+                EPOCH_STATS_OFFSET,
+                EPOCH_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitPulseOpinionCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.epoch_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
 
-        // Feedback stats initialized successfully
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "submit_feedback")]
-    pub fn submit_feedback_callback(
-        ctx: Context<SubmitFeedbackCallback>,
-        output: ComputationOutputs<SubmitFeedbackOutput>,
+    // Reveal whether the average opinion rating meets the threshold fixed at creation.
+    // Can only be done once per survey.
+    pub fn reveal_opinion_threshold(
+        ctx: Context<RevealOpinionThreshold>,
+        computation_offset: u64,
+        _opinion_id: u64,
     ) -> Result<()> {
-        let _updated_stats = match output {
-            ComputationOutputs::Success(SubmitFeedbackOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+        let opinion = &mut ctx.accounts.opinion_account;
+        let threshold = opinion.rating_threshold.ok_or(ErrorCode::NoThreshold)?;
+        require!(!opinion.threshold_revealed, ErrorCode::ThresholdAlreadyRevealed);
+        opinion.threshold_revealed = true;
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-        emit!(FeedbackSubmittedEvent {
-            post_id: ctx.accounts.post_account.post_id,
-            submitter: ctx.accounts.submitter.key(),
-        });
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.opinion_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_STATS_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+            Argument::PlaintextU8(threshold),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealOpinionThresholdCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.opinion_account.key(),
+                is_writable: false,
+            }])],
+        )?;
 
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "reveal_feedback_stats")]
-    pub fn reveal_feedback_stats_callback(
-        ctx: Context<RevealFeedbackStatsCallback>,
-        output: ComputationOutputs<RevealFeedbackStatsOutput>,
+    // Fix the margin for comparing two opinion surveys; each pair is configured once
+    pub fn configure_opinion_comparison(
+        ctx: Context<ConfigureOpinionComparison>,
+        margin: u8,
     ) -> Result<()> {
-        let stats = match output {
-            ComputationOutputs::Success(RevealFeedbackStatsOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+        require!(margin <= MAX_COMPARISON_MARGIN, ErrorCode::InvalidMargin);
+        let opinion_id_a = ctx.accounts.opinion_account_a.opinion_id;
+        let opinion_id_b = ctx.accounts.opinion_account_b.opinion_id;
+        require!(opinion_id_a != opinion_id_b, ErrorCode::SameOpinionAccount);
+        // A single ordering per pair, so (b, a) cannot be configured with another margin
+        require!(opinion_id_a < opinion_id_b, ErrorCode::OpinionPairOrder);
 
-        emit!(FeedbackStatsRevealedEvent {
-            post_id: ctx.accounts.post_account.post_id,
-            total_feedback: stats.field_0,
-            average_rating: ((stats.field_1 * 10) / stats.field_0.max(1)) as u8,
-            rating_distribution: stats.field_2,
+        let comparison = &mut ctx.accounts.comparison_account;
+        comparison.opinion_id_a = opinion_id_a;
+        comparison.opinion_id_b = opinion_id_b;
+        comparison.margin = margin;
+        comparison.revealed = false;
+
+        emit!(OpinionComparisonConfiguredEvent {
+            opinion_id_a,
+            opinion_id_b,
+            margin,
         });
 
         Ok(())
     }
 
-#[account]
-pub struct PollAccount {
-    pub poll_id: u64,
-    pub question: String,
-    pub created_at: i64,
-}
+    // Compare the average ratings of two opinion surveys, once per configured pair
+    pub fn compare_opinion_stats(
+        ctx: Context<CompareOpinionStats>,
+        computation_offset: u64,
+    ) -> Result<()> {
+        let comparison = &mut ctx.accounts.comparison_account;
+        require!(!comparison.revealed, ErrorCode::ComparisonAlreadyRevealed);
+        comparison.revealed = true;
+        let margin = comparison.margin;
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-#[account]
-pub struct OpinionAccount {
-    pub opinion_id: u64,
-    pub title: String,
-    pub content_hash: [u8; 32],
-    pub created_at: i64,
-    pub deadline: i64,
-    pub total_responses: u32,
-}
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.opinion_account_a.stats_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account_a.key(),
+                OPINION_STATS_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+            Argument::PlaintextU128(ctx.accounts.opinion_account_b.stats_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account_b.key(),
+                OPINION_STATS_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+            Argument::PlaintextU8(margin),
+        ];
 
-#[account]
-pub struct OpinionPostAccount {
-    pub post_id: u64,
-    pub encrypted_title: [u8; 32],
-    pub encrypted_content: [u8; 128],
-    pub encrypted_topic: [u8; 16],
-    pub author: Pubkey,
-    pub created_at: i64,
-    pub total_comments: u32,
-    pub total_feedback: u32,
-}
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![CompareOpinionStatsCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account_a.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account_b.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.comparison_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
 
-#[account]
-pub struct CommentAccount {
-    pub comment_id: u64,
-    pub post_id: u64,
-    pub encrypted_content: [u8; 64],
-    pub author: Pubkey,
-    pub created_at: i64,
-}
+        Ok(())
+    }
 
-#[account]
-pub struct FeedbackStatsAccount {
-    pub post_id: u64,
-    pub total_feedback: u32,
-    pub average_rating: u8,
-    pub rating_distribution: [u32; 5],
-}
+    // Re-encrypt opinion statistics to the survey authority instead of revealing them
+    pub fn reencrypt_opinion_stats(
+        ctx: Context<ReencryptOpinionStats>,
+        computation_offset: u64,
+        _opinion_id: u64,
+        owner_pub_key: [u8; 32],
+        owner_nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-#[derive(Accounts)]
-#[instruction(post_id: u64)]
-pub struct CreateOpinionPost<'info> {
-    #[account(
-        init,
-        payer = author,
-        space = 8 + 8 + 32 + 128 + 16 + 32 + 8 + 4 + 4,
-        seeds = [b"opinion_post", post_id.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub post_account: Account<'info, OpinionPostAccount>,
-    #[account(mut)]
-    pub author: Signer<'info>,
-    pub system_program: Program<'info, System>,
-    pub clock: Sysvar<'info, Clock>,
-}
+        let result = &mut ctx.accounts.stats_result_account;
+        result.subject_id = ctx.accounts.opinion_account.opinion_id;
+        result.owner = ctx.accounts.authority.key();
 
-#[derive(Accounts)]
-#[instruction(comment_id: u64)]
-pub struct AddComment<'info> {
-    #[account(
-        init,
-        payer = author,
-        space = 8 + 8 + 8 + 64 + 32 + 8,
-        seeds = [b"comment", comment_id.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub comment_account: Account<'info, CommentAccount>,
-    #[account(mut)]
-    pub post_account: Account<'info, OpinionPostAccount>,
-    #[account(mut)]
-    pub author: Signer<'info>,
-    pub system_program: Program<'info, System>,
-    pub clock: Sysvar<'info, Clock>,
-}
+        let args = vec![
+            Argument::ArcisPubkey(owner_pub_key),
+            Argument::PlaintextU128(owner_nonce),
+            Argument::PlaintextU128(ctx.accounts.opinion_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_STATS_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+        ];
 
-// Account structures - Voting
-#[queue_computation_accounts("init_vote_stats", payer)]
-#[derive(Accounts)]
-#[instruction(poll_id: u64)]
-pub struct InitVoteStats<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Account<'info, MXEAccount>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ReencryptOpinionStatsCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.stats_result_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Privately compare a respondent's submitted rating with the survey aggregate. The rating
+    // is read from the receipt stored by `submit_opinion_response`, and each respondent gets
+    // one comparison, so the aggregate cannot be probed with chosen ratings.
+    pub fn compare_opinion_response(
+        ctx: Context<CompareOpinionResponse>,
+        computation_offset: u64,
+        _opinion_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let comparison = &mut ctx.accounts.comparison_account;
+        comparison.opinion_id = ctx.accounts.opinion_account.opinion_id;
+        comparison.respondent = ctx.accounts.submitter.key();
+
+        let receipt = &ctx.accounts.response_account;
+        let args = vec![
+            Argument::ArcisPubkey(receipt.pub_key),
+            Argument::PlaintextU128(receipt.nonce),
+            Argument::Account(
+                receipt.key(),
+                RESPONSE_RATING_OFFSET,
+                RESPONSE_RATING_LEN,
+            ),
+            Argument::PlaintextU128(ctx.accounts.opinion_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_STATS_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![CompareOpinionResponseCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.comparison_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Create a public topic that posts can be filed under. Anyone can create one; the creator
+    // manages its moderators, who can in turn keep the description current.
+    pub fn create_topic(
+        ctx: Context<CreateTopic>,
+        name: String,
+        description: String,
+        moderators: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= MAX_TOPIC_NAME_LEN,
+            ErrorCode::InvalidTopicName
+        );
+        require!(
+            description.len() <= MAX_TOPIC_DESCRIPTION_LEN,
+            ErrorCode::TopicDescriptionTooLong
+        );
+        require!(moderators.len() <= MAX_TOPIC_MODERATORS, ErrorCode::TooManyModerators);
+
+        let topic_id = ctx.accounts.registry_account.assign_topic_id();
+        let topic = &mut ctx.accounts.topic_account;
+        topic.topic_id = topic_id;
+        topic.creator = ctx.accounts.creator.key();
+        topic.created_at = ctx.accounts.clock.unix_timestamp;
+        topic.post_count = 0;
+        topic.name = name;
+        topic.description = description;
+        topic.moderators = moderators;
+
+        emit!(TopicCreatedEvent {
+            topic_id,
+            creator: topic.creator,
+            name: topic.name.clone(),
+        });
+
+        Ok(())
+    }
+
+    // Update a topic's description (creator or moderator) or moderator set (creator only)
+    pub fn update_topic(
+        ctx: Context<UpdateTopic>,
+        _topic_id: u64,
+        description: Option<String>,
+        moderators: Option<Vec<Pubkey>>,
+    ) -> Result<()> {
+        let signer = ctx.accounts.signer.key();
+        let topic = &mut ctx.accounts.topic_account;
+        require!(topic.is_moderator(&signer), ErrorCode::Unauthorized);
+
+        if let Some(description) = description {
+            require!(
+                description.len() <= MAX_TOPIC_DESCRIPTION_LEN,
+                ErrorCode::TopicDescriptionTooLong
+            );
+            topic.description = description;
+        }
+        if let Some(moderators) = moderators {
+            require!(topic.creator == signer, ErrorCode::Unauthorized);
+            require!(moderators.len() <= MAX_TOPIC_MODERATORS, ErrorCode::TooManyModerators);
+            topic.moderators = moderators;
+        }
+
+        emit!(TopicUpdatedEvent {
+            topic_id: topic.topic_id,
+            updated_by: signer,
+        });
+
+        Ok(())
+    }
+
+    // Register a pseudonymous persona. The persona key is derived off-chain from a secret the
+    // user holds and never funded by their wallet; `payer` can be a relayer, so nothing on
+    // chain links the two. Posts and comments signed by the persona (with the relayer again
+    // paying as `payer`) then count towards its own post, comment and reputation counters.
+    // With a `topic_id` the persona is accepted only on posts in that topic and their
+    // threads; see `pseudonym::topic_persona_seed`.
+    pub fn register_pseudonym(ctx: Context<RegisterPseudonym>, topic_id: Option<u64>) -> Result<()> {
+        let pseudonym = &mut ctx.accounts.pseudonym_account;
+        pseudonym.persona = ctx.accounts.persona.key();
+        pseudonym.topic_id = topic_id;
+        pseudonym.created_at = ctx.accounts.clock.unix_timestamp;
+        pseudonym.post_count = 0;
+        pseudonym.comment_count = 0;
+        pseudonym.reputation = 0;
+
+        emit!(PseudonymRegisteredEvent {
+            persona: pseudonym.persona,
+            topic_id,
+            created_at: pseudonym.created_at,
+        });
+
+        Ok(())
+    }
+
+    // Create a new opinion post, optionally limiting feedback to token holders. A post with
+    // a `topic_id` must pass the topic and its next `TopicPostAccount`, which indexes the
+    // post under the topic. A persona registered for a topic can only author posts with
+    // that `topic_id`. `feedback_threshold` is the only threshold the author can later
+    // reveal the average feedback rating against.
+    // `content_len` is the full ciphertext length; if `content` holds only the first part,
+    // the rest is added with `append_post_content` before the post accepts comments.
+    // With `off_chain`, the ciphertext lives at the committed URI instead and `content`
+    // carries only its nonce and auth tag. The post id is assigned by the registry.
+    pub fn create_opinion_post(
+        ctx: Context<CreateOpinionPost>,
+        title: EncryptedBlob,
+        topic: EncryptedBlob,
+        content: EncryptedBlob,
+        off_chain: Option<OffChainContent>,
+        content_len: u32,
+        gate: Option<AccessGate>,
+        topic_id: Option<u64>,
+        feedback_threshold: Option<u8>,
+    ) -> Result<()> {
+        require!(title.ciphertext.len() <= MAX_POST_TITLE_LEN, ErrorCode::ContentTooLong);
+        require!(topic.ciphertext.len() <= MAX_POST_TOPIC_LEN, ErrorCode::ContentTooLong);
+        require!(content_len as usize <= MAX_POST_CONTENT_LEN, ErrorCode::ContentTooLong);
+        require!(
+            content.ciphertext.len() <= content_len as usize,
+            ErrorCode::ContentLengthMismatch
+        );
+        require!(
+            content::is_consistent(&off_chain, content_len),
+            ErrorCode::InvalidOffChainContent
+        );
+        require!(
+            feedback_threshold.is_none_or(|threshold| {
+                (MIN_RATING_THRESHOLD..=MAX_RATING_THRESHOLD).contains(&threshold)
+            }),
+            ErrorCode::InvalidThreshold
+        );
+        require!(
+            topic_id.is_some() == ctx.accounts.topic_account.is_some()
+                && topic_id.is_some() == ctx.accounts.topic_post_account.is_some(),
+            ErrorCode::TopicAccountsMismatch
+        );
+        PseudonymAccount::record_activity(&ctx.accounts.pseudonym_account, topic_id, |pseudonym| {
+            pseudonym.post_count += 1;
+        })?;
+
+        let post_id = ctx.accounts.registry_account.assign_post_id();
+        let post = &mut ctx.accounts.post_account;
+        post.post_id = post_id;
+        post.author = ctx.accounts.author.key();
+        post.created_at = ctx.accounts.clock.unix_timestamp;
+        post.total_comments = 0;
+        post.total_feedback = 0;
+        post.gate = gate;
+        post.topic_id = topic_id;
+        post.feedback_threshold = feedback_threshold;
+        post.threshold_revealed = false;
+        post.version = 0;
+        post.edited_at = 0;
+        post.history_hash = [0; 32];
+        post.content_len = content_len;
+        post.off_chain = off_chain;
+        post.title = title;
+        post.topic = topic;
+        post.content = content;
+        if let (Some(topic), Some(entry)) = (
+            &mut ctx.accounts.topic_account,
+            &mut ctx.accounts.topic_post_account,
+        ) {
+            entry.topic_id = topic.topic_id;
+            entry.index = topic.post_count;
+            entry.post_id = post_id;
+            topic.post_count += 1;
+        }
+
+        emit!(OpinionPostCreatedEvent {
+            post_id,
+            topic_id,
+            author: ctx.accounts.author.key(),
+        });
+
+        Ok(())
+    }
+
+    // Replace any of a post's encrypted title, topic or content. The replaced version's hash
+    // is folded into `history_hash`, so readers holding earlier ciphertexts can verify the
+    // edit history. A new `content` follows the same `off_chain`/`content_len` rules as
+    // `create_opinion_post`.
+    pub fn edit_opinion_post(
+        ctx: Context<EditOpinionPost>,
+        _post_id: u64,
+        title: Option<EncryptedBlob>,
+        topic: Option<EncryptedBlob>,
+        content: Option<EncryptedBlob>,
+        off_chain: Option<OffChainContent>,
+        content_len: u32,
+    ) -> Result<()> {
+        let post = &mut ctx.accounts.post_account;
+        require!(post.is_complete(), ErrorCode::ContentIncomplete);
+        require!(
+            title.is_some() || topic.is_some() || content.is_some(),
+            ErrorCode::EmptyEdit
+        );
+        if let Some(title) = &title {
+            require!(title.ciphertext.len() <= MAX_POST_TITLE_LEN, ErrorCode::ContentTooLong);
+        }
+        if let Some(topic) = &topic {
+            require!(topic.ciphertext.len() <= MAX_POST_TOPIC_LEN, ErrorCode::ContentTooLong);
+        }
+        match &content {
+            Some(content) => {
+                require!(content_len as usize <= MAX_POST_CONTENT_LEN, ErrorCode::ContentTooLong);
+                require!(
+                    content.ciphertext.len() <= content_len as usize,
+                    ErrorCode::ContentLengthMismatch
+                );
+                require!(
+                    content::is_consistent(&off_chain, content_len),
+                    ErrorCode::InvalidOffChainContent
+                );
+            }
+            None => require!(off_chain.is_none(), ErrorCode::InvalidOffChainContent),
+        }
+
+        let previous_hash = post.version_hash();
+        post.history_hash = hashv(&[&post.history_hash, &previous_hash]).to_bytes();
+        post.version = post.version.checked_add(1).ok_or(ErrorCode::TooManyEdits)?;
+        post.edited_at = ctx.accounts.clock.unix_timestamp;
+        if let Some(title) = title {
+            post.title = title;
+        }
+        if let Some(topic) = topic {
+            post.topic = topic;
+        }
+        if let Some(content) = content {
+            post.content = content;
+            post.content_len = content_len;
+            post.off_chain = off_chain;
+        }
+
+        emit!(OpinionPostEditedEvent {
+            post_id: post.post_id,
+            version: post.version,
+            edited_at: post.edited_at,
+            previous_hash,
+            history_hash: post.history_hash,
+        });
+
+        Ok(())
+    }
+
+    // Append the next chunk of a post's encrypted content, growing the account to fit
+    pub fn append_post_content(
+        ctx: Context<AppendPostContent>,
+        _post_id: u64,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        let post = &mut ctx.accounts.post_account;
+        let len = post.content.ciphertext.len() + chunk.len();
+        require!(len <= post.content_len as usize, ErrorCode::ContentLengthMismatch);
+        post.content.ciphertext.extend_from_slice(&chunk);
+
+        emit!(ContentAppendedEvent {
+            account: post.key(),
+            len: len as u32,
+            complete: post.is_complete(),
+        });
+
+        Ok(())
+    }
+
+    // Add a comment to an opinion post. As with posts, `content_len` is the full ciphertext
+    // length and the remainder can be added with `append_comment_content`, or the body can
+    // be stored `off_chain`. Comments are numbered per post from the post's `total_comments`,
+    // so a post's comments are the PDAs `[b"comment", post, index]` for `index < total_comments`.
+    pub fn add_comment(
+        ctx: Context<AddComment>,
+        content: EncryptedBlob,
+        off_chain: Option<OffChainContent>,
+        content_len: u32,
+    ) -> Result<()> {
+        require!(ctx.accounts.post_account.is_complete(), ErrorCode::ContentIncomplete);
+        CommentAccount::validate_content(&content, &off_chain, content_len)?;
+        PseudonymAccount::record_activity(
+            &ctx.accounts.pseudonym_account,
+            ctx.accounts.post_account.topic_id,
+            |pseudonym| pseudonym.comment_count += 1,
+        )?;
+        let comment_id = ctx.accounts.post_account.total_comments as u64;
+
+        let comment = &mut ctx.accounts.comment_account;
+        comment.comment_id = comment_id;
+        comment.post_id = ctx.accounts.post_account.post_id;
+        comment.post = ctx.accounts.post_account.key();
+        comment.author = ctx.accounts.author.key();
+        comment.created_at = ctx.accounts.clock.unix_timestamp;
+        comment.parent_comment_id = None;
+        comment.depth = 0;
+        comment.reply_count = 0;
+        comment.content_len = content_len;
+        comment.off_chain = off_chain;
+        comment.content = content;
+
+        // Increment comment count on post
+        let post = &mut ctx.accounts.post_account;
+        post.total_comments += 1;
+        // Reputation needs the commenter's grant marker, so each wallet grants once per post
+        if let (Some(recipient), Some(grant)) = (
+            &mut ctx.accounts.post_author_pseudonym,
+            &mut ctx.accounts.reputation_grant,
+        ) {
+            if !grant.granted {
+                grant.granted = true;
+                recipient.reputation += 1;
+            }
+        }
+
+        emit!(CommentAddedEvent {
+            comment_id,
+            post_id: post.post_id,
+            author: ctx.accounts.author.key(),
+            parent_comment_id: None,
+            depth: 0,
+        });
+
+        Ok(())
+    }
+
+    // Reply to an existing comment on the same post. Replies are comments with a parent:
+    // they count towards the post's `total_comments` and the parent's `reply_count`.
+    pub fn add_reply(
+        ctx: Context<AddReply>,
+        parent_comment_id: u64,
+        content: EncryptedBlob,
+        off_chain: Option<OffChainContent>,
+        content_len: u32,
+    ) -> Result<()> {
+        require!(ctx.accounts.post_account.is_complete(), ErrorCode::ContentIncomplete);
+        CommentAccount::validate_content(&content, &off_chain, content_len)?;
+        PseudonymAccount::record_activity(
+            &ctx.accounts.pseudonym_account,
+            ctx.accounts.post_account.topic_id,
+            |pseudonym| pseudonym.comment_count += 1,
+        )?;
+        let depth = ctx.accounts.parent_comment.depth + 1;
+        require!(depth <= MAX_COMMENT_DEPTH, ErrorCode::ThreadTooDeep);
+        let comment_id = ctx.accounts.post_account.total_comments as u64;
+
+        let comment = &mut ctx.accounts.comment_account;
+        comment.comment_id = comment_id;
+        comment.post_id = ctx.accounts.post_account.post_id;
+        comment.post = ctx.accounts.post_account.key();
+        comment.author = ctx.accounts.author.key();
+        comment.created_at = ctx.accounts.clock.unix_timestamp;
+        comment.parent_comment_id = Some(parent_comment_id);
+        comment.depth = depth;
+        comment.reply_count = 0;
+        comment.content_len = content_len;
+        comment.off_chain = off_chain;
+        comment.content = content;
+
+        ctx.accounts.parent_comment.reply_count += 1;
+        let post = &mut ctx.accounts.post_account;
+        post.total_comments += 1;
+        // Reputation needs the commenter's grant marker, so each wallet grants once per post
+        if let (Some(recipient), Some(grant)) = (
+            &mut ctx.accounts.parent_author_pseudonym,
+            &mut ctx.accounts.reputation_grant,
+        ) {
+            if !grant.granted {
+                grant.granted = true;
+                recipient.reputation += 1;
+            }
+        }
+
+        emit!(CommentAddedEvent {
+            comment_id,
+            post_id: post.post_id,
+            author: ctx.accounts.author.key(),
+            parent_comment_id: Some(parent_comment_id),
+            depth,
+        });
+
+        Ok(())
+    }
+
+    // Append the next chunk of a comment's encrypted content, growing the account to fit
+    pub fn append_comment_content(
+        ctx: Context<AppendCommentContent>,
+        _comment_id: u64,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        let comment = &mut ctx.accounts.comment_account;
+        let len = comment.content.ciphertext.len() + chunk.len();
+        require!(len <= comment.content_len as usize, ErrorCode::ContentLengthMismatch);
+        comment.content.ciphertext.extend_from_slice(&chunk);
+
+        emit!(ContentAppendedEvent {
+            account: comment.key(),
+            len: len as u32,
+            complete: comment.is_complete(),
+        });
+
+        Ok(())
+    }
+
+    // Delete a post, refunding its rent to the author. A tombstone keeps the post's
+    // counters so comments and feedback stats created for it remain attributable.
+    pub fn delete_opinion_post(ctx: Context<DeleteOpinionPost>, _post_id: u64) -> Result<()> {
+        let post = &ctx.accounts.post_account;
+        let tombstone = &mut ctx.accounts.tombstone_account;
+        tombstone.post_id = post.post_id;
+        tombstone.post = post.key();
+        tombstone.author = post.author;
+        tombstone.created_at = post.created_at;
+        tombstone.deleted_at = ctx.accounts.clock.unix_timestamp;
+        tombstone.total_comments = post.total_comments;
+        tombstone.total_feedback = post.total_feedback;
+        tombstone.history_hash = post.history_hash;
+
+        emit!(OpinionPostDeletedEvent {
+            post_id: post.post_id,
+            author: post.author,
+            total_comments: post.total_comments,
+        });
+
+        Ok(())
+    }
+
+    // Delete a comment, refunding its rent to the author. The post's `total_comments`
+    // is left as is; the tombstone stands in for the comment.
+    pub fn delete_comment(ctx: Context<DeleteComment>, _comment_id: u64) -> Result<()> {
+        let comment = &ctx.accounts.comment_account;
+        let tombstone = &mut ctx.accounts.tombstone_account;
+        tombstone.comment_id = comment.comment_id;
+        tombstone.post_id = comment.post_id;
+        tombstone.post = comment.post;
+        tombstone.parent_comment_id = comment.parent_comment_id;
+        tombstone.depth = comment.depth;
+        tombstone.reply_count = comment.reply_count;
+        tombstone.author = comment.author;
+        tombstone.created_at = comment.created_at;
+        tombstone.deleted_at = ctx.accounts.clock.unix_timestamp;
+
+        emit!(CommentDeletedEvent {
+            comment_id: comment.comment_id,
+            post_id: comment.post_id,
+            author: comment.author,
+        });
+
+        Ok(())
+    }
+
+    // Close the feedback stats of a deleted post, refunding their rent to the post's author
+    pub fn close_feedback_stats(ctx: Context<CloseFeedbackStats>, post_id: u64) -> Result<()> {
+        emit!(FeedbackStatsClosedEvent {
+            post_id,
+            total_feedback: ctx.accounts.feedback_stats_account.total_feedback,
+        });
+
+        Ok(())
+    }
+
+    // Initialize feedback statistics for a post
+    pub fn init_feedback_stats(
+        ctx: Context<InitFeedbackStats>,
+        computation_offset: u64,
+        _post_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.feedback_stats_account.post_id = ctx.accounts.post_account.post_id;
+        ctx.accounts.feedback_stats_account.window_started_at = Clock::get()?.unix_timestamp;
+
+        let args = vec![];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitFeedbackStatsCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.feedback_stats_account.key(),
+                is_writable: true,
+            }])],
+        )?;
+
+        Ok(())
+    }
+
+    // Submit feedback response for a post
+    pub fn submit_feedback_response(
+        ctx: Context<SubmitFeedbackResponse>,
+        computation_offset: u64,
+        _post_id: u64,
+        ciphertext_rating: [u8; 32],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let args = vec![
+            Argument::ArcisPubkey(pub_key),
+            Argument::PlaintextU128(nonce),
+            Argument::EncryptedU8(ciphertext_rating),
+            Argument::PlaintextU128(ctx.accounts.feedback_stats_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.feedback_stats_account.key(),
+                FEEDBACK_STATS_OFFSET,
+                FEEDBACK_STATS_LEN,
+            ),
+            Argument::PlaintextU128(ctx.accounts.feedback_stats_account.window_nonce),
+            Argument::Account(
+                ctx.accounts.feedback_stats_account.key(),
+                FEEDBACK_WINDOW_OFFSET,
+                FEEDBACK_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitFeedbackCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.post_account.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.submitter.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.feedback_stats_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Reveal feedback statistics for a post publicly. Only the post author can do this, so
+    // stats kept private with `reencrypt_feedback_stats` stay private.
+    pub fn reveal_feedback_stats(
+        ctx: Context<RevealFeedbackStats>,
+        computation_offset: u64,
+        _post_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.feedback_stats_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.feedback_stats_account.key(),
+                FEEDBACK_STATS_OFFSET,
+                FEEDBACK_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealFeedbackStatsCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.post_account.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.feedback_stats_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Snapshot the current feedback window for a post into an epoch account
+    pub fn rotate_feedback_window(
+        ctx: Context<RotateFeedbackWindow>,
+        computation_offset: u64,
+        _post_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let epoch = &mut ctx.accounts.epoch_account;
+        epoch.epoch = ctx.accounts.feedback_stats_account.current_epoch;
+        epoch.subject = ctx.accounts.feedback_stats_account.key();
+        epoch.started_at = ctx.accounts.feedback_stats_account.window_started_at;
+        epoch.ended_at = Clock::get()?.unix_timestamp;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.feedback_stats_account.window_nonce),
+            Argument::Account(
+                ctx.accounts.feedback_stats_account.key(),
+                FEEDBACK_WINDOW_OFFSET,
+                FEEDBACK_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RotateFeedbackWindowCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.feedback_stats_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.epoch_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Reveal the feedback statistics captured for one epoch
+    pub fn reveal_feedback_epoch_stats(
+        ctx: Context<RevealFeedbackEpochStats>,
+        computation_offset: u64,
+        _post_id: u64,
+        _epoch: u64,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.epoch_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.epoch_account.key(),
+                EPOCH_STATS_OFFSET,
+                EPOCH_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealFeedbackEpochStatsCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.feedback_stats_account.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.epoch_account.key(),
+                    is_writable: false,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Reveal whether the average feedback rating for a post meets the threshold fixed when
+    // the post was created. Can only be done once per post.
+    pub fn reveal_feedback_threshold(
+        ctx: Context<RevealFeedbackThreshold>,
+        computation_offset: u64,
+        _post_id: u64,
+    ) -> Result<()> {
+        let post = &mut ctx.accounts.post_account;
+        let threshold = post.feedback_threshold.ok_or(ErrorCode::NoThreshold)?;
+        require!(!post.threshold_revealed, ErrorCode::ThresholdAlreadyRevealed);
+        post.threshold_revealed = true;
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.feedback_stats_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.feedback_stats_account.key(),
+                FEEDBACK_STATS_OFFSET,
+                FEEDBACK_STATS_LEN,
+            ),
+            Argument::PlaintextU8(threshold),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealFeedbackThresholdCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.post_account.key(),
+                is_writable: false,
+            }])],
+        )?;
+
+        Ok(())
+    }
+
+    // Re-encrypt feedback statistics for a post to its author
+    pub fn reencrypt_feedback_stats(
+        ctx: Context<ReencryptFeedbackStats>,
+        computation_offset: u64,
+        _post_id: u64,
+        owner_pub_key: [u8; 32],
+        owner_nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let result = &mut ctx.accounts.stats_result_account;
+        result.subject_id = ctx.accounts.post_account.post_id;
+        result.owner = ctx.accounts.author.key();
+
+        let args = vec![
+            Argument::ArcisPubkey(owner_pub_key),
+            Argument::PlaintextU128(owner_nonce),
+            Argument::PlaintextU128(ctx.accounts.feedback_stats_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.feedback_stats_account.key(),
+                FEEDBACK_STATS_OFFSET,
+                FEEDBACK_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ReencryptFeedbackStatsCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.post_account.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.stats_result_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Create a forecasting question that accepts encrypted probabilities until the deadline.
+    // The forecast id is assigned by the registry.
+    pub fn create_forecast(
+        ctx: Context<CreateForecast>,
+        question: String,
+        deadline: i64,
+    ) -> Result<()> {
+        require!(
+            question.len() <= MAX_FORECAST_QUESTION_LEN,
+            ErrorCode::QuestionTooLong
+        );
+        require!(
+            deadline > ctx.accounts.clock.unix_timestamp,
+            ErrorCode::InvalidDeadline
+        );
+
+        let forecast_id = ctx.accounts.registry_account.assign_forecast_id();
+        let forecast = &mut ctx.accounts.forecast_account;
+        forecast.forecast_id = forecast_id;
+        forecast.authority = ctx.accounts.authority.key();
+        forecast.created_at = ctx.accounts.clock.unix_timestamp;
+        forecast.deadline = deadline;
+        forecast.total_forecasts = 0;
+        forecast.resolved = false;
+        forecast.outcome = false;
+        forecast.leaderboard_len = 0;
+        forecast.stats_initialized = false;
+        forecast.question = question;
+
+        emit!(ForecastCreatedEvent {
+            forecast_id,
+            authority: forecast.authority,
+            deadline,
+        });
+
+        Ok(())
+    }
+
+    // Initialize encrypted forecast aggregates
+    pub fn init_forecast_stats(
+        ctx: Context<InitForecastStats>,
+        computation_offset: u64,
+        _forecast_id: u64,
+    ) -> Result<()> {
+        let forecast = &mut ctx.accounts.forecast_account;
+        require!(!forecast.stats_initialized, ErrorCode::StatsAlreadyInitialized);
+        forecast.stats_initialized = true;
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let args = vec![];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitForecastStatsCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.forecast_account.key(),
+                is_writable: true,
+            }])],
+        )?;
+
+        Ok(())
+    }
+
+    // Submit an encrypted probability forecast (0-100) before the deadline
+    pub fn submit_forecast(
+        ctx: Context<SubmitForecast>,
+        computation_offset: u64,
+        _forecast_id: u64,
+        pseudonym: [u8; 32],
+        ciphertext_probability: [u8; 32],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let forecast = &ctx.accounts.forecast_account;
+        require!(forecast.stats_initialized, ErrorCode::StatsNotInitialized);
+        require!(
+            Clock::get()?.unix_timestamp < forecast.deadline,
+            ErrorCode::DeadlinePassed
+        );
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        // Keep the participant's ciphertext so it can be scored after resolution. The entry
+        // is keyed by the pseudonym alone, so it does not name the submitting wallet; the
+        // `voted` marker only records that the wallet took part, once.
+        let entry = &mut ctx.accounts.entry_account;
+        entry.forecast_id = ctx.accounts.forecast_account.forecast_id;
+        entry.pseudonym = pseudonym;
+        entry.encryption_key = pub_key;
+        entry.nonce = nonce;
+        entry.encrypted_probability = ciphertext_probability;
+        entry.scored = false;
+
+        let args = vec![
+            Argument::ArcisPubkey(pub_key),
+            Argument::PlaintextU128(nonce),
+            Argument::EncryptedU8(ciphertext_probability),
+            Argument::PlaintextU128(ctx.accounts.forecast_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.forecast_account.key(),
+                FORECAST_STATS_OFFSET,
+                FORECAST_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitForecastCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.forecast_account.key(),
+                is_writable: true,
+            }])],
+        )?;
+
+        Ok(())
+    }
+
+    // Reveal the mean forecast once the submission window has closed
+    pub fn reveal_forecast_mean(
+        ctx: Context<RevealForecastMean>,
+        computation_offset: u64,
+        _forecast_id: u64,
+    ) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= ctx.accounts.forecast_account.deadline,
+            ErrorCode::DeadlineNotReached
+        );
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.forecast_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.forecast_account.key(),
+                FORECAST_STATS_OFFSET,
+                FORECAST_STATS_LEN,
+            ),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealForecastMeanCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.forecast_account.key(),
+                is_writable: false,
+            }])],
+        )?;
+
+        Ok(())
+    }
+
+    // Record the resolved outcome of a forecasting question
+    pub fn resolve_forecast(
+        ctx: Context<ResolveForecast>,
+        _forecast_id: u64,
+        outcome: bool,
+    ) -> Result<()> {
+        let forecast = &mut ctx.accounts.forecast_account;
+        require!(
+            ctx.accounts.clock.unix_timestamp >= forecast.deadline,
+            ErrorCode::DeadlineNotReached
+        );
+        require!(!forecast.resolved, ErrorCode::ForecastAlreadyResolved);
+
+        forecast.resolved = true;
+        forecast.outcome = outcome;
+
+        emit!(ForecastResolvedEvent {
+            forecast_id: forecast.forecast_id,
+            outcome,
+        });
+
+        Ok(())
+    }
+
+    // Score one participant's forecast against the resolved outcome
+    pub fn score_forecast(
+        ctx: Context<ScoreForecast>,
+        computation_offset: u64,
+        _forecast_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.forecast_account.resolved,
+            ErrorCode::ForecastNotResolved
+        );
+        require!(!ctx.accounts.entry_account.scored, ErrorCode::AlreadyScored);
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let entry = &ctx.accounts.entry_account;
+        let args = vec![
+            Argument::ArcisPubkey(entry.encryption_key),
+            Argument::PlaintextU128(entry.nonce),
+            Argument::EncryptedU8(entry.encrypted_probability),
+            Argument::PlaintextBool(ctx.accounts.forecast_account.outcome),
+        ];
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ScoreForecastCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.forecast_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.entry_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Queue an encrypted vote for batched aggregation
+    pub fn enqueue_vote(
+        ctx: Context<EnqueueVote>,
+        _poll_id: u64,
+        ciphertext: [u8; 32],
+        pub_key: [u8; 32],
+        nonce: u128,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.poll_account.is_multi_option(),
+            ErrorCode::WrongPollKind
+        );
+        require!(
+            ctx.accounts.poll_account.is_open(Clock::get()?.unix_timestamp),
+            ErrorCode::DeadlinePassed
+        );
+        require!(
+            merkle::is_allowlisted(
+                ctx.accounts.poll_account.eligible_root,
+                &ctx.accounts.submitter.key(),
+                &proof,
+            ),
+            ErrorCode::NotAllowlisted
+        );
+        let subject = ctx.accounts.poll_account.key();
+        let queue = &mut ctx.accounts.queue_account;
+        queue.subject = subject;
+        queue.push(QueuedBallot {
+            encryption_key: pub_key,
+            nonce,
+            ciphertext,
+        })?;
+
+        emit!(BallotEnqueuedEvent { subject });
+
+        Ok(())
+    }
+
+    // Aggregate up to BALLOT_BATCH_SIZE queued votes into the poll tally
+    pub fn aggregate_vote_batch(
+        ctx: Context<AggregateVoteBatch>,
+        computation_offset: u64,
+        _poll_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_account.stats_initialized,
+            ErrorCode::StatsNotInitialized
+        );
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let mut args = ctx.accounts.queue_account.start_batch(Argument::EncryptedBool)?;
+        args.extend([
+            Argument::PlaintextU128(ctx.accounts.poll_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.poll_account.key(),
+                POLL_STATS_OFFSET,
+                POLL_STATS_LEN,
+            ),
+        ]);
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![AggregateVoteBatchCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.poll_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.queue_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Queue an encrypted opinion response for batched aggregation
+    pub fn enqueue_opinion_response(
+        ctx: Context<EnqueueOpinionResponse>,
+        _opinion_id: u64,
+        ciphertext: [u8; 32],
+        pub_key: [u8; 32],
+        nonce: u128,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let opinion = &ctx.accounts.opinion_account;
+        require!(!opinion.is_recurring(), ErrorCode::PulseResponseRequired);
+        require!(opinion.is_open(Clock::get()?.unix_timestamp), ErrorCode::DeadlinePassed);
+        require!(
+            merkle::is_allowlisted(
+                ctx.accounts.opinion_account.eligible_root,
+                &ctx.accounts.submitter.key(),
+                &proof,
+            ),
+            ErrorCode::NotAllowlisted
+        );
+        require!(
+            !ctx.accounts.opinion_account.has_eligibility_rule(),
+            ErrorCode::EligibilityCheckRequired
+        );
+        let subject = ctx.accounts.opinion_account.key();
+        let queue = &mut ctx.accounts.queue_account;
+        queue.subject = subject;
+        queue.push(QueuedBallot {
+            encryption_key: pub_key,
+            nonce,
+            ciphertext,
+        })?;
+
+        emit!(BallotEnqueuedEvent { subject });
+
+        Ok(())
+    }
+
+    // Aggregate up to BALLOT_BATCH_SIZE queued opinion responses
+    pub fn aggregate_opinion_batch(
+        ctx: Context<AggregateOpinionBatch>,
+        computation_offset: u64,
+        _opinion_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.opinion_account.stats_initialized,
+            ErrorCode::StatsNotInitialized
+        );
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let mut args = ctx.accounts.queue_account.start_batch(Argument::EncryptedU8)?;
+        args.extend([
+            Argument::PlaintextU128(ctx.accounts.opinion_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_STATS_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+            Argument::PlaintextU128(ctx.accounts.opinion_account.window_nonce),
+            Argument::Account(
+                ctx.accounts.opinion_account.key(),
+                OPINION_WINDOW_OFFSET,
+                OPINION_STATS_LEN,
+            ),
+        ]);
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![AggregateOpinionBatchCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.opinion_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.queue_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Queue an encrypted feedback response for batched aggregation
+    pub fn enqueue_feedback_response(
+        ctx: Context<EnqueueFeedbackResponse>,
+        _post_id: u64,
+        ciphertext: [u8; 32],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let subject = ctx.accounts.feedback_stats_account.key();
+        let queue = &mut ctx.accounts.queue_account;
+        queue.subject = subject;
+        queue.push(QueuedBallot {
+            encryption_key: pub_key,
+            nonce,
+            ciphertext,
+        })?;
+
+        emit!(BallotEnqueuedEvent { subject });
+
+        Ok(())
+    }
+
+    // Aggregate up to BALLOT_BATCH_SIZE queued feedback responses
+    pub fn aggregate_feedback_batch(
+        ctx: Context<AggregateFeedbackBatch>,
+        computation_offset: u64,
+        _post_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.feedback_stats_account.stats_initialized,
+            ErrorCode::StatsNotInitialized
+        );
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let mut args = ctx.accounts.queue_account.start_batch(Argument::EncryptedU8)?;
+        args.extend([
+            Argument::PlaintextU128(ctx.accounts.feedback_stats_account.stats_nonce),
+            Argument::Account(
+                ctx.accounts.feedback_stats_account.key(),
+                FEEDBACK_STATS_OFFSET,
+                FEEDBACK_STATS_LEN,
+            ),
+            Argument::PlaintextU128(ctx.accounts.feedback_stats_account.window_nonce),
+            Argument::Account(
+                ctx.accounts.feedback_stats_account.key(),
+                FEEDBACK_WINDOW_OFFSET,
+                FEEDBACK_STATS_LEN,
+            ),
+        ]);
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![AggregateFeedbackBatchCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.feedback_stats_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.queue_account.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    // Callbacks for MPC computation results
+    #[arcium_callback(encrypted_ix = "init_vote_stats")]
+    pub fn init_vote_stats_callback(
+        ctx: Context<InitVoteStatsCallback>,
+        output: ComputationOutputs<InitVoteStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(InitVoteStatsOutput { field_0 }) => field_0,
+            _ => {
+                // Let the poll creator queue the initialization again
+                ctx.accounts.poll_account.stats_initialized = false;
+                return Ok(());
+            }
+        };
+
+        let poll = &mut ctx.accounts.poll_account;
+        poll.encrypted_stats = stats.ciphertexts;
+        poll.stats_nonce = stats.nonce;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote")]
+    pub fn vote_callback(
+        ctx: Context<VoteCallback>,
+        output: ComputationOutputs<VoteOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(VoteOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let poll = &mut ctx.accounts.poll_account;
+        poll.encrypted_stats = updated_stats.ciphertexts;
+        poll.stats_nonce = updated_stats.nonce;
+
+        emit!(VoteSubmittedEvent {
+            poll_id: ctx.accounts.poll_account.poll_id,
+            submitter: ctx.accounts.submitter.key(),
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_result")]
+    pub fn reveal_result_callback(
+        ctx: Context<RevealResultCallback>,
+        output: ComputationOutputs<RevealResultOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(RevealResultOutput { field_0 }) => field_0,
+            _ => {
+                // Let the poll creator queue the reveal again
+                ctx.accounts.poll_account.result_revealed = false;
+                return Ok(());
+            }
+        };
+
+        emit!(PollResultRevealedEvent {
+            poll_id: ctx.accounts.poll_account.poll_id,
+            quorum_met: result.field_0,
+            passed: result.field_1,
+            overflowed: result.field_2,
+            pass_threshold: ctx.accounts.poll_account.pass_threshold,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_option_vote_stats")]
+    pub fn init_option_vote_stats_callback(
+        ctx: Context<InitOptionVoteStatsCallback>,
+        output: ComputationOutputs<InitOptionVoteStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(InitOptionVoteStatsOutput { field_0 }) => field_0,
+            _ => {
+                // Let the poll creator queue the initialization again
+                ctx.accounts.poll_account.stats_initialized = false;
+                return Ok(());
+            }
+        };
+
+        let poll = &mut ctx.accounts.poll_account;
+        poll.encrypted_option_stats = stats.ciphertexts;
+        poll.option_stats_nonce = stats.nonce;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_option")]
+    pub fn vote_option_callback(
+        ctx: Context<VoteOptionCallback>,
+        output: ComputationOutputs<VoteOptionOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(VoteOptionOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let poll = &mut ctx.accounts.poll_account;
+        poll.encrypted_option_stats = updated_stats.ciphertexts;
+        poll.option_stats_nonce = updated_stats.nonce;
+
+        emit!(VoteSubmittedEvent {
+            poll_id: ctx.accounts.poll_account.poll_id,
+            submitter: ctx.accounts.submitter.key(),
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_option_result")]
+    pub fn reveal_option_result_callback(
+        ctx: Context<RevealOptionResultCallback>,
+        output: ComputationOutputs<RevealOptionResultOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(RevealOptionResultOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let poll = &mut ctx.accounts.poll_account;
+        let runoff_needed = result.field_2 != NO_OPTION;
+        if runoff_needed {
+            poll.runoff_candidates = Some([result.field_1, result.field_2]);
+        }
+
+        emit!(OptionPollResultRevealedEvent {
+            poll_id: poll.poll_id,
+            round: poll.round,
+            majority: result.field_0,
+            leading_option: result.field_1,
+            runner_up_option: result.field_2,
+            runoff_needed,
+            overflowed: result.field_3,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_opinion_stats")]
+    pub fn init_opinion_stats_callback(
+        ctx: Context<InitOpinionStatsCallback>,
+        output: ComputationOutputs<InitOpinionStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(InitOpinionStatsOutput { field_0 }) => field_0,
+            _ => {
+                // Let the authority queue the initialization again
+                ctx.accounts.opinion_account.stats_initialized = false;
+                return Ok(());
+            }
+        };
+
+        let opinion = &mut ctx.accounts.opinion_account;
+        opinion.encrypted_stats = stats.field_0.ciphertexts;
+        opinion.stats_nonce = stats.field_0.nonce;
+        opinion.encrypted_window_stats = stats.field_1.ciphertexts;
+        opinion.window_nonce = stats.field_1.nonce;
+        opinion.encrypted_rejected = stats.field_2.ciphertexts;
+        opinion.rejected_nonce = stats.field_2.nonce;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_opinion")]
+    pub fn submit_opinion_callback(
+        ctx: Context<SubmitOpinionCallback>,
+        output: ComputationOutputs<SubmitOpinionOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(SubmitOpinionOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let opinion = &mut ctx.accounts.opinion_account;
+        opinion.encrypted_stats = updated_stats.field_0.ciphertexts;
+        opinion.stats_nonce = updated_stats.field_0.nonce;
+        opinion.encrypted_window_stats = updated_stats.field_1.ciphertexts;
+        opinion.window_nonce = updated_stats.field_1.nonce;
+
+        emit!(OpinionSubmittedEvent {
+            opinion_id: ctx.accounts.opinion_account.opinion_id,
+            submitter: ctx.accounts.submitter.key(),
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_eligible_opinion")]
+    pub fn submit_eligible_opinion_callback(
+        ctx: Context<SubmitEligibleOpinionCallback>,
+        output: ComputationOutputs<SubmitEligibleOpinionOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(SubmitEligibleOpinionOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let opinion = &mut ctx.accounts.opinion_account;
+        opinion.encrypted_stats = updated_stats.field_0.ciphertexts;
+        opinion.stats_nonce = updated_stats.field_0.nonce;
+        opinion.encrypted_window_stats = updated_stats.field_1.ciphertexts;
+        opinion.window_nonce = updated_stats.field_1.nonce;
+        opinion.encrypted_rejected = updated_stats.field_2.ciphertexts;
+        opinion.rejected_nonce = updated_stats.field_2.nonce;
+
+        // Emitted for every submission so eligibility cannot be inferred from events
+        emit!(OpinionSubmittedEvent {
+            opinion_id: ctx.accounts.opinion_account.opinion_id,
+            submitter: ctx.accounts.submitter.key(),
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_rejected_responses")]
+    pub fn reveal_rejected_responses_callback(
+        ctx: Context<RevealRejectedResponsesCallback>,
+        output: ComputationOutputs<RevealRejectedResponsesOutput>,
+    ) -> Result<()> {
+        let rejected = match output {
+            ComputationOutputs::Success(RevealRejectedResponsesOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        emit!(RejectedResponsesRevealedEvent {
+            opinion_id: ctx.accounts.opinion_account.opinion_id,
+            rejected,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_opinion_stats")]
+    pub fn reveal_opinion_stats_callback(
+        ctx: Context<RevealOpinionStatsCallback>,
+        output: ComputationOutputs<RevealOpinionStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(RevealOpinionStatsOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        let average_rating = RatingX10::from_sum(stats.field_1, stats.field_0)
+            .ok_or(ErrorCode::AverageOutOfRange)?;
+
+        emit!(OpinionStatsRevealedEvent {
+            opinion_id: ctx.accounts.opinion_account.opinion_id,
+            total_responses: stats.field_0,
+            average_rating: average_rating.get(),
+            rating_distribution: stats.field_2,
+            overflowed: stats.field_3,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "rotate_opinion_window")]
+    pub fn rotate_opinion_window_callback(
+        ctx: Context<RotateOpinionWindowCallback>,
+        output: ComputationOutputs<RotateOpinionWindowOutput>,
+    ) -> Result<()> {
+        let rotated = match output {
+            ComputationOutputs::Success(RotateOpinionWindowOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let epoch = &mut ctx.accounts.epoch_account;
+        epoch.encrypted_stats = rotated.field_0.ciphertexts;
+        epoch.stats_nonce = rotated.field_0.nonce;
+
+        let opinion = &mut ctx.accounts.opinion_account;
+        opinion.encrypted_window_stats = rotated.field_1.ciphertexts;
+        opinion.window_nonce = rotated.field_1.nonce;
+        opinion.current_epoch += 1;
+        opinion.window_started_at = epoch.ended_at;
+
+        emit!(OpinionEpochSnapshotEvent {
+            opinion_id: opinion.opinion_id,
+            epoch: epoch.epoch,
+            started_at: epoch.started_at,
+            ended_at: epoch.ended_at,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_opinion_epoch_stats")]
+    pub fn reveal_opinion_epoch_stats_callback(
+        ctx: Context<RevealOpinionEpochStatsCallback>,
+        output: ComputationOutputs<RevealOpinionEpochStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(RevealOpinionEpochStatsOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        let average_rating = RatingX10::from_sum(stats.field_1, stats.field_0)
+            .ok_or(ErrorCode::AverageOutOfRange)?;
+
+        emit!(OpinionEpochStatsRevealedEvent {
+            opinion_id: ctx.accounts.opinion_account.opinion_id,
+            epoch: ctx.accounts.epoch_account.epoch,
+            total_responses: stats.field_0,
+            average_rating: average_rating.get(),
+            rating_distribution: stats.field_2,
+            overflowed: stats.field_3,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_pulse_stats")]
+    pub fn init_pulse_stats_callback(
+        ctx: Context<InitPulseStatsCallback>,
+        output: ComputationOutputs<InitPulseStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(InitPulseStatsOutput { field_0 }) => field_0,
+            _ => {
+                // Let the authority queue the initialization again
+                ctx.accounts.epoch_account.stats_initialized = false;
+                return Ok(());
+            }
+        };
+
+        let period = &mut ctx.accounts.epoch_account;
+        period.encrypted_stats = stats.ciphertexts;
+        period.stats_nonce = stats.nonce;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_pulse_opinion")]
+    pub fn submit_pulse_opinion_callback(
+        ctx: Context<SubmitPulseOpinionCallback>,
+        output: ComputationOutputs<SubmitPulseOpinionOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(SubmitPulseOpinionOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let period = &mut ctx.accounts.epoch_account;
+        period.encrypted_stats = updated_stats.ciphertexts;
+        period.stats_nonce = updated_stats.nonce;
+
+        emit!(PulseResponseSubmittedEvent {
+            opinion_id: ctx.accounts.opinion_account.opinion_id,
+            epoch: period.epoch,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_opinion_threshold")]
+    pub fn reveal_opinion_threshold_callback(
+        ctx: Context<RevealOpinionThresholdCallback>,
+        output: ComputationOutputs<RevealOpinionThresholdOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(RevealOpinionThresholdOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        emit!(OpinionThresholdRevealedEvent {
+            opinion_id: ctx.accounts.opinion_account.opinion_id,
+            threshold: result.field_1,
+            above_threshold: result.field_0,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "compare_opinion_stats")]
+    pub fn compare_opinion_stats_callback(
+        ctx: Context<CompareOpinionStatsCallback>,
+        output: ComputationOutputs<CompareOpinionStatsOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(CompareOpinionStatsOutput { field_0 }) => field_0,
+            _ => {
+                // Let the authority queue the comparison again
+                ctx.accounts.comparison_account.revealed = false;
+                return Ok(());
+            }
+        };
+
+        emit!(OpinionComparisonRevealedEvent {
+            opinion_id_a: ctx.accounts.opinion_account_a.opinion_id,
+            opinion_id_b: ctx.accounts.opinion_account_b.opinion_id,
+            margin: result.field_1,
+            outcome: result.field_0,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reencrypt_opinion_stats")]
+    pub fn reencrypt_opinion_stats_callback(
+        ctx: Context<ReencryptOpinionStatsCallback>,
+        output: ComputationOutputs<ReencryptOpinionStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(ReencryptOpinionStatsOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let result = &mut ctx.accounts.stats_result_account;
+        result.encryption_key = stats.encryption_key;
+        result.nonce = stats.nonce;
+        result.encrypted_stats = stats.ciphertexts;
+
+        emit!(OpinionStatsReencryptedEvent {
+            opinion_id: ctx.accounts.opinion_account.opinion_id,
+            owner: result.owner,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "compare_opinion_response")]
+    pub fn compare_opinion_response_callback(
+        ctx: Context<CompareOpinionResponseCallback>,
+        output: ComputationOutputs<CompareOpinionResponseOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(CompareOpinionResponseOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let comparison = &mut ctx.accounts.comparison_account;
+        comparison.encryption_key = result.encryption_key;
+        comparison.nonce = result.nonce;
+        comparison.encrypted_result = result.ciphertexts;
+
+        emit!(PersonalComparisonReadyEvent {
+            opinion_id: ctx.accounts.opinion_account.opinion_id,
+            respondent: comparison.respondent,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_feedback_stats")]
+    pub fn init_feedback_stats_callback(
+        ctx: Context<InitFeedbackStatsCallback>,
+        output: ComputationOutputs<InitFeedbackStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(InitFeedbackStatsOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let feedback_stats = &mut ctx.accounts.feedback_stats_account;
+        feedback_stats.encrypted_stats = stats.field_0.ciphertexts;
+        feedback_stats.stats_nonce = stats.field_0.nonce;
+        feedback_stats.encrypted_window_stats = stats.field_1.ciphertexts;
+        feedback_stats.window_nonce = stats.field_1.nonce;
+        feedback_stats.stats_initialized = true;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_feedback")]
+    pub fn submit_feedback_callback(
+        ctx: Context<SubmitFeedbackCallback>,
+        output: ComputationOutputs<SubmitFeedbackOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(SubmitFeedbackOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let feedback_stats = &mut ctx.accounts.feedback_stats_account;
+        feedback_stats.encrypted_stats = updated_stats.field_0.ciphertexts;
+        feedback_stats.stats_nonce = updated_stats.field_0.nonce;
+        feedback_stats.encrypted_window_stats = updated_stats.field_1.ciphertexts;
+        feedback_stats.window_nonce = updated_stats.field_1.nonce;
+
+        emit!(FeedbackSubmittedEvent {
+            post_id: ctx.accounts.post_account.post_id,
+            submitter: ctx.accounts.submitter.key(),
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_feedback_stats")]
+    pub fn reveal_feedback_stats_callback(
+        ctx: Context<RevealFeedbackStatsCallback>,
+        output: ComputationOutputs<RevealFeedbackStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(RevealFeedbackStatsOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        let average_rating = RatingX10::from_sum(stats.field_1, stats.field_0)
+            .ok_or(ErrorCode::AverageOutOfRange)?
+            .get();
+
+        let feedback_stats = &mut ctx.accounts.feedback_stats_account;
+        feedback_stats.total_feedback = stats.field_0;
+        feedback_stats.average_rating = average_rating;
+        feedback_stats.rating_distribution = stats.field_2;
+        feedback_stats.overflowed = stats.field_3;
+
+        emit!(FeedbackStatsRevealedEvent {
+            post_id: ctx.accounts.post_account.post_id,
+            total_feedback: stats.field_0,
+            average_rating,
+            rating_distribution: stats.field_2,
+            overflowed: stats.field_3,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "rotate_feedback_window")]
+    pub fn rotate_feedback_window_callback(
+        ctx: Context<RotateFeedbackWindowCallback>,
+        output: ComputationOutputs<RotateFeedbackWindowOutput>,
+    ) -> Result<()> {
+        let rotated = match output {
+            ComputationOutputs::Success(RotateFeedbackWindowOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let epoch = &mut ctx.accounts.epoch_account;
+        epoch.encrypted_stats = rotated.field_0.ciphertexts;
+        epoch.stats_nonce = rotated.field_0.nonce;
+
+        let feedback_stats = &mut ctx.accounts.feedback_stats_account;
+        feedback_stats.encrypted_window_stats = rotated.field_1.ciphertexts;
+        feedback_stats.window_nonce = rotated.field_1.nonce;
+        feedback_stats.current_epoch += 1;
+        feedback_stats.window_started_at = epoch.ended_at;
+
+        emit!(FeedbackEpochSnapshotEvent {
+            post_id: feedback_stats.post_id,
+            epoch: epoch.epoch,
+            started_at: epoch.started_at,
+            ended_at: epoch.ended_at,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_feedback_epoch_stats")]
+    pub fn reveal_feedback_epoch_stats_callback(
+        ctx: Context<RevealFeedbackEpochStatsCallback>,
+        output: ComputationOutputs<RevealFeedbackEpochStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(RevealFeedbackEpochStatsOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        let average_rating = RatingX10::from_sum(stats.field_1, stats.field_0)
+            .ok_or(ErrorCode::AverageOutOfRange)?;
+
+        emit!(FeedbackEpochStatsRevealedEvent {
+            post_id: ctx.accounts.feedback_stats_account.post_id,
+            epoch: ctx.accounts.epoch_account.epoch,
+            total_feedback: stats.field_0,
+            average_rating: average_rating.get(),
+            rating_distribution: stats.field_2,
+            overflowed: stats.field_3,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_feedback_threshold")]
+    pub fn reveal_feedback_threshold_callback(
+        ctx: Context<RevealFeedbackThresholdCallback>,
+        output: ComputationOutputs<RevealFeedbackThresholdOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(RevealFeedbackThresholdOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        emit!(FeedbackThresholdRevealedEvent {
+            post_id: ctx.accounts.post_account.post_id,
+            threshold: result.field_1,
+            above_threshold: result.field_0,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reencrypt_feedback_stats")]
+    pub fn reencrypt_feedback_stats_callback(
+        ctx: Context<ReencryptFeedbackStatsCallback>,
+        output: ComputationOutputs<ReencryptFeedbackStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(ReencryptFeedbackStatsOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let result = &mut ctx.accounts.stats_result_account;
+        result.encryption_key = stats.encryption_key;
+        result.nonce = stats.nonce;
+        result.encrypted_stats = stats.ciphertexts;
+
+        emit!(FeedbackStatsReencryptedEvent {
+            post_id: ctx.accounts.post_account.post_id,
+            owner: result.owner,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_forecast_stats")]
+    pub fn init_forecast_stats_callback(
+        ctx: Context<InitForecastStatsCallback>,
+        output: ComputationOutputs<InitForecastStatsOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(InitForecastStatsOutput { field_0 }) => field_0,
+            _ => {
+                // Let the authority queue the initialization again
+                ctx.accounts.forecast_account.stats_initialized = false;
+                return Ok(());
+            }
+        };
+
+        let forecast = &mut ctx.accounts.forecast_account;
+        forecast.encrypted_stats = stats.ciphertexts;
+        forecast.stats_nonce = stats.nonce;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_forecast")]
+    pub fn submit_forecast_callback(
+        ctx: Context<SubmitForecastCallback>,
+        output: ComputationOutputs<SubmitForecastOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(SubmitForecastOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let forecast = &mut ctx.accounts.forecast_account;
+        forecast.encrypted_stats = updated_stats.ciphertexts;
+        forecast.stats_nonce = updated_stats.nonce;
+        forecast.total_forecasts += 1;
+
+        emit!(ForecastSubmittedEvent {
+            forecast_id: forecast.forecast_id,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_forecast_mean")]
+    pub fn reveal_forecast_mean_callback(
+        ctx: Context<RevealForecastMeanCallback>,
+        output: ComputationOutputs<RevealForecastMeanOutput>,
+    ) -> Result<()> {
+        let stats = match output {
+            ComputationOutputs::Success(RevealForecastMeanOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        emit!(ForecastMeanRevealedEvent {
+            forecast_id: ctx.accounts.forecast_account.forecast_id,
+            total_forecasts: stats.field_0,
+            mean_probability: (stats.field_1 / stats.field_0.max(1)) as u8,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "score_forecast")]
+    pub fn score_forecast_callback(
+        ctx: Context<ScoreForecastCallback>,
+        output: ComputationOutputs<ScoreForecastOutput>,
+    ) -> Result<()> {
+        let result = match output {
+            ComputationOutputs::Success(ScoreForecastOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        let brier_bucket = result.field_1;
+
+        let entry = &mut ctx.accounts.entry_account;
+        entry.scored = true;
+        entry.score_nonce = result.field_0.nonce;
+        entry.encrypted_score = result.field_0.ciphertexts;
+
+        let forecast = &mut ctx.accounts.forecast_account;
+        forecast.record_score(entry.pseudonym, brier_bucket);
+
+        emit!(ForecastScoredEvent {
+            forecast_id: forecast.forecast_id,
+            pseudonym: entry.pseudonym,
+            brier_bucket,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "aggregate_vote_batch")]
+    pub fn aggregate_vote_batch_callback(
+        ctx: Context<AggregateVoteBatchCallback>,
+        output: ComputationOutputs<AggregateVoteBatchOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(AggregateVoteBatchOutput { field_0 }) => field_0,
+            _ => {
+                ctx.accounts.queue_account.abort_batch();
+                return Ok(());
+            }
+        };
+
+        let poll = &mut ctx.accounts.poll_account;
+        poll.encrypted_stats = updated_stats.ciphertexts;
+        poll.stats_nonce = updated_stats.nonce;
+
+        let queue = &mut ctx.accounts.queue_account;
+        let count = queue.finish_batch();
+
+        emit!(BallotBatchAggregatedEvent {
+            subject: queue.subject,
+            count,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "aggregate_opinion_batch")]
+    pub fn aggregate_opinion_batch_callback(
+        ctx: Context<AggregateOpinionBatchCallback>,
+        output: ComputationOutputs<AggregateOpinionBatchOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(AggregateOpinionBatchOutput { field_0 }) => field_0,
+            _ => {
+                ctx.accounts.queue_account.abort_batch();
+                return Ok(());
+            }
+        };
+
+        let opinion = &mut ctx.accounts.opinion_account;
+        opinion.encrypted_stats = updated_stats.field_0.ciphertexts;
+        opinion.stats_nonce = updated_stats.field_0.nonce;
+        opinion.encrypted_window_stats = updated_stats.field_1.ciphertexts;
+        opinion.window_nonce = updated_stats.field_1.nonce;
+
+        let queue = &mut ctx.accounts.queue_account;
+        let count = queue.finish_batch();
+
+        emit!(BallotBatchAggregatedEvent {
+            subject: queue.subject,
+            count,
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "aggregate_feedback_batch")]
+    pub fn aggregate_feedback_batch_callback(
+        ctx: Context<AggregateFeedbackBatchCallback>,
+        output: ComputationOutputs<AggregateFeedbackBatchOutput>,
+    ) -> Result<()> {
+        let updated_stats = match output {
+            ComputationOutputs::Success(AggregateFeedbackBatchOutput { field_0 }) => field_0,
+            _ => {
+                ctx.accounts.queue_account.abort_batch();
+                return Ok(());
+            }
+        };
+
+        let feedback_stats = &mut ctx.accounts.feedback_stats_account;
+        feedback_stats.encrypted_stats = updated_stats.field_0.ciphertexts;
+        feedback_stats.stats_nonce = updated_stats.field_0.nonce;
+        feedback_stats.encrypted_window_stats = updated_stats.field_1.ciphertexts;
+        feedback_stats.window_nonce = updated_stats.field_1.nonce;
+
+        let queue = &mut ctx.accounts.queue_account;
+        let count = queue.finish_batch();
+
+        emit!(BallotBatchAggregatedEvent {
+            subject: queue.subject,
+            count,
+        });
+
+        Ok(())
+    }
+
+/// Sequential id counters for posts, polls, opinion surveys, topics and forecasts.
+///
+/// Ids start at 0, so every id below a counter belongs to an account (or a tombstone).
+#[account]
+pub struct RegistryAccount {
+    pub next_post_id: u64,
+    pub next_poll_id: u64,
+    pub next_opinion_id: u64,
+    pub next_topic_id: u64,
+    pub next_forecast_id: u64,
+}
+
+impl RegistryAccount {
+    pub fn assign_post_id(&mut self) -> u64 {
+        let id = self.next_post_id;
+        self.next_post_id += 1;
+        id
+    }
+
+    pub fn assign_poll_id(&mut self) -> u64 {
+        let id = self.next_poll_id;
+        self.next_poll_id += 1;
+        id
+    }
+
+    pub fn assign_opinion_id(&mut self) -> u64 {
+        let id = self.next_opinion_id;
+        self.next_opinion_id += 1;
+        id
+    }
+
+    pub fn assign_topic_id(&mut self) -> u64 {
+        let id = self.next_topic_id;
+        self.next_topic_id += 1;
+        id
+    }
+
+    pub fn assign_forecast_id(&mut self) -> u64 {
+        let id = self.next_forecast_id;
+        self.next_forecast_id += 1;
+        id
+    }
+}
+
+#[account]
+pub struct PollAccount {
+    pub poll_id: u64,
+    pub stats_nonce: u128,
+    pub encrypted_stats: [[u8; 32]; 3],
+    pub option_stats_nonce: u128,
+    pub encrypted_option_stats: [[u8; 32]; MAX_POLL_OPTIONS as usize + 1],
+    pub question: String,
+    pub created_at: i64,
+    pub authority: Pubkey,
+    pub eligible_root: Option<[u8; 32]>,
+    pub gate: Option<AccessGate>,
+    pub quorum: Quorum,
+    pub pass_threshold: PassThreshold,
+    pub option_count: u8,
+    pub runoff_on_no_majority: bool,
+    pub deadline: i64,
+    pub round: u8,
+    pub parent_poll: Option<Pubkey>,
+    pub runoff_candidates: Option<[u8; 2]>,
+    pub runoff_poll: Option<Pubkey>,
+    pub stats_initialized: bool,  // set once `init_vote_stats` or `init_option_vote_stats` is queued
+    pub result_revealed: bool,  // set once `reveal_result` is queued
+}
+
+impl PollAccount {
+    /// Whether this poll tallies several options rather than yes/no
+    pub fn is_multi_option(&self) -> bool {
+        self.option_count > 0
+    }
+
+    /// Whether ballots are still accepted at `now`
+    pub fn is_open(&self, now: i64) -> bool {
+        self.deadline == 0 || now < self.deadline
+    }
+}
+
+/// Minimum participation before a poll result is revealed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Quorum {
+    /// At least this many ballots
+    Ballots(u64),
+    /// At least `percent` of `eligible_voters`, rounded up
+    PercentOfEligible { percent: u8, eligible_voters: u64 },
+}
+
+impl Quorum {
+    /// Returns the minimum ballot count, or `None` if the quorum is not satisfiable
+    pub fn required_ballots(&self) -> Option<u64> {
+        match *self {
+            Quorum::Ballots(min) => (min > 0).then_some(min),
+            Quorum::PercentOfEligible {
+                percent,
+                eligible_voters,
+            } => {
+                if percent == 0 || percent > 100 || eligible_voters == 0 {
+                    return None;
+                }
+                let required = (eligible_voters as u128 * percent as u128).div_ceil(100);
+                u64::try_from(required).ok()
+            }
+        }
+    }
+}
+
+/// Fraction of the ballots cast that must be yes for a poll to pass, e.g. 2/3 or 60/100
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PassThreshold {
+    pub numerator: u16,
+    pub denominator: u16,
+}
+
+impl PassThreshold {
+    /// A simple majority: strictly more yes than no
+    pub const MAJORITY: Self = Self {
+        numerator: 1,
+        denominator: 2,
+    };
+
+    /// Thresholds must be at least a simple majority and below 1, so a unanimous vote passes
+    pub fn is_valid(&self) -> bool {
+        self.numerator < self.denominator
+            && 2 * self.numerator as u32 >= self.denominator as u32
+    }
+}
+
+#[account]
+pub struct OpinionAccount {
+    pub opinion_id: u64,
+    pub stats_nonce: u128,
+    pub encrypted_stats: [[u8; 32]; 8],
+    pub window_nonce: u128,
+    pub encrypted_window_stats: [[u8; 32]; 8],
+    pub rejected_nonce: u128,
+    pub encrypted_rejected: [[u8; 32]; 1],
+    pub current_epoch: u64,
+    pub window_started_at: i64,
+    pub title: String,
+    pub content_hash: [u8; 32],
+    pub created_at: i64,
+    pub deadline: i64,
+    pub total_responses: u32,
+    pub authority: Pubkey,
+    pub period: i64,
+    pub eligible_attributes: u64,
+    pub eligible_root: Option<[u8; 32]>,
+    pub gate: Option<AccessGate>,
+    pub rating_threshold: Option<u8>,
+    pub threshold_revealed: bool,
+    pub stats_initialized: bool,  // set once `init_opinion_stats` is queued
+}
+
+impl OpinionAccount {
+    /// Whether responses must go through the private eligibility check
+    pub fn has_eligibility_rule(&self) -> bool {
+        self.eligible_attributes != 0
+    }
+
+    /// Whether this survey runs in recurring periods rather than as a one-off
+    pub fn is_recurring(&self) -> bool {
+        self.period > 0
+    }
+
+    /// Whether responses are still accepted at `now`
+    pub fn is_open(&self, now: i64) -> bool {
+        now < self.deadline
+    }
+
+    /// Returns the start and end of period `epoch`, if it begins before the deadline
+    pub fn epoch_bounds(&self, epoch: u64) -> Option<(i64, i64)> {
+        if !self.is_recurring() {
+            return None;
+        }
+        let offset = i64::try_from(epoch).ok()?.checked_mul(self.period)?;
+        let started_at = self.created_at.checked_add(offset)?;
+        if started_at >= self.deadline {
+            return None;
+        }
+        Some((started_at, started_at.checked_add(self.period)?))
+    }
+
+    /// Returns the index of the period containing `now`, if the survey is still running
+    pub fn epoch_at(&self, now: i64) -> Option<u64> {
+        if !self.is_recurring() || now < self.created_at || now >= self.deadline {
+            return None;
+        }
+        u64::try_from((now - self.created_at) / self.period).ok()
+    }
+}
+
+/// A client-side AEAD ciphertext stored with the parameters needed to decrypt it.
+///
+/// The nonce is sized for XChaCha20-Poly1305; ciphers with shorter nonces zero-pad it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct EncryptedBlob {
+    pub nonce: [u8; 24],
+    pub auth_tag: [u8; 16],
+    pub ciphertext: Vec<u8>,
+}
+
+impl EncryptedBlob {
+    /// Serialized size of a blob holding `len` ciphertext bytes
+    pub const fn space(len: usize) -> usize {
+        24 + 16 + 4 + len
+    }
+}
+
+#[account]
+pub struct OpinionPostAccount {
+    pub post_id: u64,
+    pub author: Pubkey,
+    pub created_at: i64,
+    pub total_comments: u32,
+    pub total_feedback: u32,
+    pub gate: Option<AccessGate>,
+    pub topic_id: Option<u64>,
+    pub feedback_threshold: Option<u8>,
+    pub threshold_revealed: bool,
+    pub version: u32,
+    pub edited_at: i64,
+    pub history_hash: [u8; 32],  // hash(prev history_hash || replaced version_hash) per edit
+    pub content_len: u32,
+    pub off_chain: Option<OffChainContent>,
+    pub title: EncryptedBlob,
+    pub topic: EncryptedBlob,
+    pub content: EncryptedBlob,
+}
+
+impl OpinionPostAccount {
+    /// Account size for the given variable-length fields
+    pub fn space(
+        title: &EncryptedBlob,
+        topic: &EncryptedBlob,
+        content: &EncryptedBlob,
+        off_chain: &Option<OffChainContent>,
+    ) -> usize {
+        8 + 8 + 32 + 8 + 4 + 4 + GATE_SPACE + 9 + 2 + 1 + 4 + 8 + 32 + 4
+            + OffChainContent::option_space(off_chain)
+            + EncryptedBlob::space(title.ciphertext.len())
+            + EncryptedBlob::space(topic.ciphertext.len())
+            + EncryptedBlob::space(content.ciphertext.len())
+    }
+
+    /// Account size after an edit replacing the given fields
+    pub fn edited_space(
+        &self,
+        title: &Option<EncryptedBlob>,
+        topic: &Option<EncryptedBlob>,
+        content: &Option<EncryptedBlob>,
+        off_chain: &Option<OffChainContent>,
+    ) -> usize {
+        Self::space(
+            title.as_ref().unwrap_or(&self.title),
+            topic.as_ref().unwrap_or(&self.topic),
+            content.as_ref().unwrap_or(&self.content),
+            if content.is_some() { off_chain } else { &self.off_chain },
+        )
+    }
+
+    /// Whether every chunk of the encrypted content has been uploaded
+    pub fn is_complete(&self) -> bool {
+        self.content.ciphertext.len() == self.content_len as usize
+    }
+
+    /// Hash of the current title, topic and content ciphertexts.
+    ///
+    /// Each blob is hashed as length, nonce, auth tag and ciphertext; off-chain content
+    /// contributes its committed hash.
+    pub fn version_hash(&self) -> [u8; 32] {
+        let title_len = (self.title.ciphertext.len() as u32).to_le_bytes();
+        let topic_len = (self.topic.ciphertext.len() as u32).to_le_bytes();
+        let content_len = (self.content.ciphertext.len() as u32).to_le_bytes();
+        let off_chain_hash = self
+            .off_chain
+            .as_ref()
+            .map_or([0; 32], |off_chain| off_chain.content_hash);
+        hashv(&[
+            &title_len,
+            &self.title.nonce,
+            &self.title.auth_tag,
+            &self.title.ciphertext,
+            &topic_len,
+            &self.topic.nonce,
+            &self.topic.auth_tag,
+            &self.topic.ciphertext,
+            &content_len,
+            &self.content.nonce,
+            &self.content.auth_tag,
+            &self.content.ciphertext,
+            &off_chain_hash,
+        ])
+        .to_bytes()
+    }
+}
+
+#[account]
+pub struct CommentAccount {
+    pub comment_id: u64,  // index among the post's comments
+    pub post_id: u64,
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub created_at: i64,
+    pub parent_comment_id: Option<u64>,  // None for top-level comments
+    pub depth: u16,
+    pub reply_count: u32,
+    pub content_len: u32,
+    pub off_chain: Option<OffChainContent>,
+    pub content: EncryptedBlob,
+}
+
+impl CommentAccount {
+    /// Account size for the given variable-length fields
+    pub fn space(content: &EncryptedBlob, off_chain: &Option<OffChainContent>) -> usize {
+        8 + 8 + 8 + 32 + 32 + 8 + 9 + 2 + 4 + 4
+            + OffChainContent::option_space(off_chain)
+            + EncryptedBlob::space(content.ciphertext.len())
+    }
+
+    /// Checks a new comment body against the size and off-chain rules
+    pub fn validate_content(
+        content: &EncryptedBlob,
+        off_chain: &Option<OffChainContent>,
+        content_len: u32,
+    ) -> Result<()> {
+        require!(
+            content_len as usize <= MAX_COMMENT_CONTENT_LEN,
+            ErrorCode::ContentTooLong
+        );
+        require!(
+            content.ciphertext.len() <= content_len as usize,
+            ErrorCode::ContentLengthMismatch
+        );
+        require!(
+            content::is_consistent(off_chain, content_len),
+            ErrorCode::InvalidOffChainContent
+        );
+        Ok(())
+    }
+
+    /// Whether every chunk of the encrypted content has been uploaded
+    pub fn is_complete(&self) -> bool {
+        self.content.ciphertext.len() == self.content_len as usize
+    }
+}
+
+/// A public category that posts can be filed under
+#[account]
+pub struct TopicAccount {
+    pub topic_id: u64,
+    pub creator: Pubkey,
+    pub created_at: i64,
+    pub post_count: u64,
+    pub name: String,
+    pub description: String,
+    pub moderators: Vec<Pubkey>,
+}
+
+impl TopicAccount {
+    /// Whether `key` may moderate the topic; the creator always can
+    pub fn is_moderator(&self, key: &Pubkey) -> bool {
+        self.creator == *key || self.moderators.contains(key)
+    }
+}
+
+/// Entry `index` of a topic's post list, at `[b"topic_post", topic_id, index]`
+#[account]
+pub struct TopicPostAccount {
+    pub topic_id: u64,
+    pub index: u64,
+    pub post_id: u64,
+}
+
+/// A persona that signs posts and comments in place of a wallet
+#[account]
+pub struct PseudonymAccount {
+    pub persona: Pubkey,
+    pub topic_id: Option<u64>,  // None for personas usable in any topic
+    pub created_at: i64,
+    pub post_count: u32,
+    pub comment_count: u32,
+    // Distinct commenters per post who replied to this persona's posts or comments. Each
+    // wallet grants at most one point per post, but wallets are free, so this measures
+    // unauthenticated popularity rather than trust.
+    pub reputation: u64,
+}
+
+impl PseudonymAccount {
+    /// Whether the persona may author in the given topic
+    pub fn is_registered_for(&self, topic_id: Option<u64>) -> bool {
+        self.topic_id.is_none_or(|registered| topic_id == Some(registered))
+    }
+
+    /// Applies `update` to the persona stored at the author's `[b"pseudonym", author]` PDA.
+    ///
+    /// An empty account means the author is a plain wallet and nothing happens. Otherwise
+    /// the persona must be registered for `topic_id`, so a topic persona cannot skip the
+    /// check by leaving its account out.
+    pub fn record_activity(
+        info: &AccountInfo,
+        topic_id: Option<u64>,
+        update: impl FnOnce(&mut Self),
+    ) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(
+            *info.owner,
+            crate::ID,
+            anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+        );
+        let mut pseudonym = Self::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(
+            pseudonym.is_registered_for(topic_id),
+            ErrorCode::PseudonymTopicMismatch
+        );
+        update(&mut pseudonym);
+        pseudonym.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+    }
+}
+
+/// Marks that `author` has granted reputation on a post, at `[b"reputation", post, author]`
+#[account]
+pub struct ReputationGrantAccount {
+    pub granted: bool,
+}
+
+/// What remains of a deleted `OpinionPostAccount`
+#[account]
+pub struct PostTombstoneAccount {
+    pub post_id: u64,
+    pub post: Pubkey,  // address of the closed post account
+    pub author: Pubkey,
+    pub created_at: i64,
+    pub deleted_at: i64,
+    pub total_comments: u32,
+    pub total_feedback: u32,
+    pub history_hash: [u8; 32],
+}
+
+/// What remains of a deleted `CommentAccount`
+#[account]
+pub struct CommentTombstoneAccount {
+    pub comment_id: u64,
+    pub post_id: u64,
+    pub post: Pubkey,
+    pub parent_comment_id: Option<u64>,
+    pub depth: u16,
+    pub reply_count: u32,
+    pub author: Pubkey,
+    pub created_at: i64,
+    pub deleted_at: i64,
+}
+
+#[account]
+pub struct FeedbackStatsAccount {
+    pub post_id: u64,
+    pub stats_nonce: u128,
+    pub encrypted_stats: [[u8; 32]; 8],
+    pub window_nonce: u128,
+    pub encrypted_window_stats: [[u8; 32]; 8],
+    pub current_epoch: u64,
+    pub window_started_at: i64,
+    pub total_feedback: u64,
+    pub average_rating: u8,
+    pub rating_distribution: [u64; 5],
+    pub overflowed: bool,
+    pub stats_initialized: bool,  // set by `init_feedback_stats_callback`
+}
+
+#[account]
+pub struct StatsEpochAccount {
+    pub epoch: u64,
+    pub stats_nonce: u128,
+    pub encrypted_stats: [[u8; 32]; 8],
+    pub subject: Pubkey,
+    pub started_at: i64,
+    pub ended_at: i64,
+    pub stats_initialized: bool,  // set once `init_pulse_stats` is queued for a survey period
+}
+
+#[account]
+pub struct EncryptedStatsResultAccount {
+    pub subject_id: u64,
+    pub owner: Pubkey,
+    pub encryption_key: [u8; 32],
+    pub nonce: u128,
+    pub encrypted_stats: [[u8; 32]; 8],
+}
+
+/// A respondent's eligibility attribute, encrypted by the survey authority that issued it.
+///
+/// Only the authority can create it, so the attribute counted for a response is never
+/// self-declared.
+#[account]
+pub struct AttributeCredentialAccount {
+    pub opinion_id: u64,
+    pub respondent: Pubkey,
+    pub pub_key: [u8; 32],
+    pub nonce: u128,
+    pub encrypted_attribute: [u8; 32],
+}
+
+/// Records that a wallet has submitted a ballot or response to a poll, survey or survey
+/// period, at `[b"voted", subject, submitter]`.
+///
+/// Every submit and enqueue path creates it with `init`, so an allowlist member cannot
+/// submit twice, whether through the same path or through the direct and batched paths.
+#[account]
+pub struct VotedAccount {}
+
+/// A respondent's submitted rating, kept encrypted under their own key
+#[account]
+pub struct ResponseReceiptAccount {
+    pub opinion_id: u64,
+    pub respondent: Pubkey,
+    pub pub_key: [u8; 32],
+    pub nonce: u128,
+    pub encrypted_rating: [u8; 32],
+}
+
+/// Margin fixed for one pair of opinion surveys, which may be compared once
+#[account]
+pub struct OpinionComparisonAccount {
+    pub opinion_id_a: u64,
+    pub opinion_id_b: u64,
+    pub margin: u8,
+    pub revealed: bool,
+}
+
+#[account]
+pub struct PersonalComparisonAccount {
+    pub opinion_id: u64,
+    pub respondent: Pubkey,
+    pub encryption_key: [u8; 32],
+    pub nonce: u128,
+    pub encrypted_result: [[u8; 32]; 2],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LeaderboardEntry {
+    pub pseudonym: [u8; 32],
+    pub brier_bucket: u8,
+}
+
+#[account]
+pub struct ForecastAccount {
+    pub forecast_id: u64,
+    pub stats_nonce: u128,
+    pub encrypted_stats: [[u8; 32]; 2],
+    pub authority: Pubkey,
+    pub created_at: i64,
+    pub deadline: i64,
+    pub total_forecasts: u32,
+    pub resolved: bool,
+    pub outcome: bool,
+    pub leaderboard_len: u8,
+    pub leaderboard: [LeaderboardEntry; FORECAST_LEADERBOARD_SIZE],
+    pub question: String,
+    pub stats_initialized: bool,  // set once `init_forecast_stats` is queued
+}
+
+impl ForecastAccount {
+    /// Inserts a score bucket into the leaderboard, keeping the lowest buckets first.
+    ///
+    /// Entries in the same bucket keep the order they were scored in.
+    pub fn record_score(&mut self, pseudonym: [u8; 32], brier_bucket: u8) {
+        let len = self.leaderboard_len as usize;
+        let position = self.leaderboard[..len]
+            .iter()
+            .position(|entry| brier_bucket < entry.brier_bucket)
+            .unwrap_or(len);
+        if position >= FORECAST_LEADERBOARD_SIZE {
+            return;
+        }
+
+        let last = len.min(FORECAST_LEADERBOARD_SIZE - 1);
+        self.leaderboard.copy_within(position..last, position + 1);
+        self.leaderboard[position] = LeaderboardEntry {
+            pseudonym,
+            brier_bucket,
+        };
+        self.leaderboard_len = (last + 1) as u8;
+    }
+}
+
+#[account]
+pub struct ForecastEntryAccount {
+    pub forecast_id: u64,
+    pub pseudonym: [u8; 32],
+    pub encryption_key: [u8; 32],
+    pub nonce: u128,
+    pub encrypted_probability: [u8; 32],
+    pub scored: bool,
+    pub score_nonce: u128,
+    pub encrypted_score: [[u8; 32]; 2],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct QueuedBallot {
+    pub encryption_key: [u8; 32],
+    pub nonce: u128,
+    pub ciphertext: [u8; 32],
+}
+
+/// Ring buffer of ballots waiting to be aggregated, oldest at `head`
+#[account]
+pub struct BallotQueueAccount {
+    pub subject: Pubkey,
+    pub head: u8,
+    pub len: u8,
+    pub in_flight: u8,
+    pub ballots: [QueuedBallot; BALLOT_QUEUE_CAPACITY],
+}
+
+impl BallotQueueAccount {
+    fn slot(&self, offset: usize) -> usize {
+        (self.head as usize + offset) % BALLOT_QUEUE_CAPACITY
+    }
+
+    /// Appends a ballot, failing once the queue is at capacity.
+    pub fn push(&mut self, ballot: QueuedBallot) -> Result<()> {
+        let len = self.len as usize;
+        require!(len < BALLOT_QUEUE_CAPACITY, ErrorCode::BallotQueueFull);
+        let slot = self.slot(len);
+        self.ballots[slot] = ballot;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Marks up to a batch of the oldest ballots as in flight and returns them as circuit
+    /// arguments.
+    ///
+    /// Every batch slot is emitted so the argument list matches the fixed-size batch
+    /// circuits; unused slots carry zeroed ciphertexts, and the trailing count tells the
+    /// circuit how many slots are real.
+    pub fn start_batch(&mut self, encrypted: fn([u8; 32]) -> Argument) -> Result<Vec<Argument>> {
+        require!(self.in_flight == 0, ErrorCode::BatchInFlight);
+        require!(self.len > 0, ErrorCode::BallotQueueEmpty);
+        self.in_flight = self.len.min(BALLOT_BATCH_SIZE as u8);
+
+        let mut args = Vec::with_capacity(BALLOT_BATCH_SIZE * 3 + 1);
+        for i in 0..BALLOT_BATCH_SIZE {
+            let ballot = if i < self.in_flight as usize {
+                self.ballots[self.slot(i)]
+            } else {
+                QueuedBallot::default()
+            };
+            args.push(Argument::ArcisPubkey(ballot.encryption_key));
+            args.push(Argument::PlaintextU128(ballot.nonce));
+            args.push(encrypted(ballot.ciphertext));
+        }
+        args.push(Argument::PlaintextU8(self.in_flight));
+        Ok(args)
+    }
+
+    /// Releases the in-flight ballots after an aborted aggregation so they can be retried.
+    pub fn abort_batch(&mut self) {
+        self.in_flight = 0;
+    }
+
+    /// Drops the ballots that were aggregated, keeping any still waiting behind them.
+    pub fn finish_batch(&mut self) -> u8 {
+        let done = self.in_flight;
+        for i in 0..done as usize {
+            let slot = self.slot(i);
+            self.ballots[slot] = QueuedBallot::default();
+        }
+        self.head = self.slot(done as usize) as u8;
+        self.len -= done;
+        self.in_flight = 0;
+        done
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitRegistry<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 8 + 8 + 8 + 8,
+        seeds = [b"registry"],
+        bump
+    )]
+    pub registry_account: Account<'info, RegistryAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreatePoll<'info> {
+    #[account(mut, seeds = [b"registry"], bump)]
+    pub registry_account: Account<'info, RegistryAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = POLL_ACCOUNT_SPACE,
+        seeds = [b"poll", registry_account.next_poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(comment_id: u64, chunk: Vec<u8>)]
+pub struct AppendCommentContent<'info> {
+    #[account(
+        mut,
+        has_one = author @ ErrorCode::Unauthorized,
+        realloc = comment_account.to_account_info().data_len() + chunk.len(),
+        realloc::payer = payer,
+        realloc::zero = false,
+        seeds = [
+            b"comment",
+            comment_account.post.as_ref(),
+            comment_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub comment_account: Account<'info, CommentAccount>,
+    pub author: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct DeleteOpinionPost<'info> {
+    #[account(
+        mut,
+        has_one = author @ ErrorCode::Unauthorized,
+        close = author,
+        seeds = [b"opinion_post", post_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 32 + 32 + 8 + 8 + 4 + 4 + 32,
+        seeds = [b"opinion_post_tombstone", post_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tombstone_account: Account<'info, PostTombstoneAccount>,
+    #[account(mut)]
+    pub author: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(comment_id: u64)]
+pub struct DeleteComment<'info> {
+    #[account(
+        mut,
+        has_one = author @ ErrorCode::Unauthorized,
+        close = author,
+        seeds = [
+            b"comment",
+            comment_account.post.as_ref(),
+            comment_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub comment_account: Account<'info, CommentAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 8 + 32 + 9 + 2 + 4 + 32 + 8 + 8,
+        seeds = [
+            b"comment_tombstone",
+            comment_account.post.as_ref(),
+            comment_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub tombstone_account: Account<'info, CommentTombstoneAccount>,
+    #[account(mut)]
+    pub author: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct CloseFeedbackStats<'info> {
+    #[account(
+        has_one = author @ ErrorCode::Unauthorized,
+        seeds = [b"opinion_post_tombstone", post_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tombstone_account: Account<'info, PostTombstoneAccount>,
+    #[account(
+        mut,
+        close = author,
+        seeds = [b"feedback_stats", tombstone_account.post.as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(mut)]
+    pub author: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateOpinion<'info> {
+    #[account(mut, seeds = [b"registry"], bump)]
+    pub registry_account: Account<'info, RegistryAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 8 + 16 + 32 * 8 + 16 + 32 * 8 + 16 + 32 + 8 + 8
+            + 4 + MAX_OPINION_TITLE_LEN
+            + 32 + 8 + 8 + 4 + 32 + 8 + 8 + 1 + 32 + GATE_SPACE + 2 + 1 + 1,
+        seeds = [b"opinion", registry_account.next_opinion_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterPseudonym<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 32 + 9 + 8 + 4 + 4 + 8,
+        seeds = [b"pseudonym", persona.key().as_ref()],
+        bump
+    )]
+    pub pseudonym_account: Account<'info, PseudonymAccount>,
+    pub persona: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+pub struct CreateTopic<'info> {
+    #[account(mut, seeds = [b"registry"], bump)]
+    pub registry_account: Account<'info, RegistryAccount>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + 8 + 32 + 8 + 8
+            + 4 + MAX_TOPIC_NAME_LEN
+            + 4 + MAX_TOPIC_DESCRIPTION_LEN
+            + 4 + 32 * MAX_TOPIC_MODERATORS,
+        seeds = [b"topic", registry_account.next_topic_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub topic_account: Account<'info, TopicAccount>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(topic_id: u64)]
+pub struct UpdateTopic<'info> {
+    #[account(
+        mut,
+        seeds = [b"topic", topic_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub topic_account: Account<'info, TopicAccount>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    title: EncryptedBlob,
+    topic: EncryptedBlob,
+    content: EncryptedBlob,
+    off_chain: Option<OffChainContent>,
+    content_len: u32,
+    gate: Option<AccessGate>,
+    topic_id: Option<u64>,
+)]
+pub struct CreateOpinionPost<'info> {
+    #[account(mut, seeds = [b"registry"], bump)]
+    pub registry_account: Account<'info, RegistryAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = OpinionPostAccount::space(&title, &topic, &content, &off_chain),
+        seeds = [b"opinion_post", registry_account.next_post_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    pub author: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"pseudonym", author.key().as_ref()], bump)]
+    /// CHECK: the author's persona, if registered; read by `PseudonymAccount::record_activity`
+    pub pseudonym_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"topic", topic_id.unwrap_or_default().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub topic_account: Option<Account<'info, TopicAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 8 + 8,
+        seeds = [
+            b"topic_post",
+            topic_id.unwrap_or_default().to_le_bytes().as_ref(),
+            topic_account
+                .as_ref()
+                .map_or(0, |topic| topic.post_count)
+                .to_le_bytes()
+                .as_ref(),
+        ],
+        bump
+    )]
+    pub topic_post_account: Option<Account<'info, TopicPostAccount>>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    post_id: u64,
+    title: Option<EncryptedBlob>,
+    topic: Option<EncryptedBlob>,
+    content: Option<EncryptedBlob>,
+    off_chain: Option<OffChainContent>,
+)]
+pub struct EditOpinionPost<'info> {
+    #[account(
+        mut,
+        has_one = author @ ErrorCode::Unauthorized,
+        realloc = post_account.edited_space(&title, &topic, &content, &off_chain),
+        realloc::payer = payer,
+        realloc::zero = false,
+        seeds = [b"opinion_post", post_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    pub author: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64, chunk: Vec<u8>)]
+pub struct AppendPostContent<'info> {
+    #[account(
+        mut,
+        has_one = author @ ErrorCode::Unauthorized,
+        realloc = post_account.to_account_info().data_len() + chunk.len(),
+        realloc::payer = payer,
+        realloc::zero = false,
+        seeds = [b"opinion_post", post_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    pub author: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(content: EncryptedBlob, off_chain: Option<OffChainContent>)]
+pub struct AddComment<'info> {
+    #[account(mut)]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = CommentAccount::space(&content, &off_chain),
+        seeds = [
+            b"comment",
+            post_account.key().as_ref(),
+            (post_account.total_comments as u64).to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub comment_account: Account<'info, CommentAccount>,
+    pub author: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"pseudonym", author.key().as_ref()], bump)]
+    /// CHECK: the author's persona, if registered; read by `PseudonymAccount::record_activity`
+    pub pseudonym_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"pseudonym", post_account.author.as_ref()],
+        bump,
+        constraint = post_account.author != author.key() @ ErrorCode::SelfReputation,
+    )]
+    pub post_author_pseudonym: Option<Account<'info, PseudonymAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 1,
+        seeds = [b"reputation", post_account.key().as_ref(), author.key().as_ref()],
+        bump
+    )]
+    pub reputation_grant: Option<Account<'info, ReputationGrantAccount>>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    parent_comment_id: u64,
+    content: EncryptedBlob,
+    off_chain: Option<OffChainContent>,
+)]
+pub struct AddReply<'info> {
+    #[account(mut)]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    // Deriving the parent from the post's key ties it to the same post
+    #[account(
+        mut,
+        seeds = [
+            b"comment",
+            post_account.key().as_ref(),
+            parent_comment_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub parent_comment: Account<'info, CommentAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = CommentAccount::space(&content, &off_chain),
+        seeds = [
+            b"comment",
+            post_account.key().as_ref(),
+            (post_account.total_comments as u64).to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub comment_account: Account<'info, CommentAccount>,
+    pub author: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [b"pseudonym", author.key().as_ref()], bump)]
+    /// CHECK: the author's persona, if registered; read by `PseudonymAccount::record_activity`
+    pub pseudonym_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"pseudonym", parent_comment.author.as_ref()],
+        bump,
+        constraint = parent_comment.author != author.key() @ ErrorCode::SelfReputation,
+    )]
+    pub parent_author_pseudonym: Option<Account<'info, PseudonymAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 1,
+        seeds = [b"reputation", post_account.key().as_ref(), author.key().as_ref()],
+        bump
+    )]
+    pub reputation_grant: Option<Account<'info, ReputationGrantAccount>>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// Account structures - Voting
+#[queue_computation_accounts("init_vote_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, poll_id: u64)]
+pub struct InitVoteStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub poll_account: Account<'info, PollAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[queue_computation_accounts("vote", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, poll_id: u64)]
+pub struct Vote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = gate::is_satisfied(
+            &poll_account.gate,
+            &submitter.key(),
+            gate_token_account.as_deref(),
+            gate_metadata_account.as_deref(),
+        ) @ ErrorCode::GateNotSatisfied
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+    pub submitter: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8,
+        seeds = [b"voted", poll_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    pub gate_metadata_account: Option<Account<'info, MetadataAccount>>,
+}
+
+#[queue_computation_accounts("reveal_result", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, poll_id: u64)]
+pub struct RevealResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_RESULT)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub poll_account: Account<'info, PollAccount>,
+    pub authority: Signer<'info>,
+}
+
+// Account structures - Opinions
+#[queue_computation_accounts("init_option_vote_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, poll_id: u64)]
+pub struct InitOptionVoteStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_OPTION_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub poll_account: Account<'info, PollAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[queue_computation_accounts("vote_option", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, poll_id: u64)]
+pub struct VoteOption<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_OPTION)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = gate::is_satisfied(
+            &poll_account.gate,
+            &submitter.key(),
+            gate_token_account.as_deref(),
+            gate_metadata_account.as_deref(),
+        ) @ ErrorCode::GateNotSatisfied
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+    pub submitter: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8,
+        seeds = [b"voted", poll_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    pub gate_metadata_account: Option<Account<'info, MetadataAccount>>,
+}
+
+#[queue_computation_accounts("reveal_option_result", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, poll_id: u64)]
+pub struct RevealOptionResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPTION_RESULT)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub poll_account: Account<'info, PollAccount>,
+}
+
+#[derive(Accounts)]
+pub struct OpenRunoff<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(mut, seeds = [b"registry"], bump)]
+    pub registry_account: Account<'info, RegistryAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = POLL_ACCOUNT_SPACE,
+        seeds = [b"poll", registry_account.next_poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub runoff_poll_account: Account<'info, PollAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[queue_computation_accounts("init_opinion_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct InitOpinionStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_OPINION_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[queue_computation_accounts("submit_opinion", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct SubmitOpinionResponse<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_OPINION)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = gate::is_satisfied(
+            &opinion_account.gate,
+            &submitter.key(),
+            gate_token_account.as_deref(),
+            gate_metadata_account.as_deref(),
+        ) @ ErrorCode::GateNotSatisfied
+    )]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub submitter: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8,
+        seeds = [b"voted", opinion_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    pub gate_metadata_account: Option<Account<'info, MetadataAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 32 + 32 + 16 + 32,
+        seeds = [
+            b"response",
+            opinion_account.key().as_ref(),
+            submitter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub response_account: Account<'info, ResponseReceiptAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(opinion_id: u64, respondent: Pubkey)]
+pub struct IssueAttributeCredential<'info> {
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 8 + 32 + 32 + 16 + 32,
+        seeds = [
+            b"credential",
+            opinion_account.key().as_ref(),
+            respondent.as_ref(),
+        ],
+        bump
+    )]
+    pub credential_account: Account<'info, AttributeCredentialAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("submit_eligible_opinion", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct SubmitEligibleOpinionResponse<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_ELIGIBLE_OPINION)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = gate::is_satisfied(
+            &opinion_account.gate,
+            &submitter.key(),
+            gate_token_account.as_deref(),
+            gate_metadata_account.as_deref(),
+        ) @ ErrorCode::GateNotSatisfied
+    )]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub submitter: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8,
+        seeds = [b"voted", opinion_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    #[account(
+        seeds = [
+            b"credential",
+            opinion_account.key().as_ref(),
+            submitter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub credential_account: Account<'info, AttributeCredentialAccount>,
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    pub gate_metadata_account: Option<Account<'info, MetadataAccount>>,
+}
+
+#[queue_computation_accounts("reveal_rejected_responses", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct RevealRejectedResponses<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_REJECTED_RESPONSES)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[queue_computation_accounts("reveal_opinion_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct RevealOpinionStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPINION_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[queue_computation_accounts("rotate_opinion_window", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct RotateOpinionWindow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROTATE_OPINION_WINDOW)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 16 + 32 * 8 + 32 + 8 + 8 + 1,
+        seeds = [
+            b"opinion_epoch",
+            opinion_account.key().as_ref(),
+            opinion_account.current_epoch.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+}
+
+#[queue_computation_accounts("reveal_opinion_epoch_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64, epoch: u64)]
+pub struct RevealOpinionEpochStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPINION_EPOCH_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"opinion_epoch", opinion_account.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+}
+
+#[queue_computation_accounts("init_pulse_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64, epoch: u64)]
+pub struct InitPulseStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_PULSE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 8 + 16 + 32 * 8 + 32 + 8 + 8 + 1,
+        seeds = [b"opinion_epoch", opinion_account.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+}
+
+#[queue_computation_accounts("submit_pulse_opinion", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct SubmitPulseResponse<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_PULSE_OPINION)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = gate::is_satisfied(
+            &opinion_account.gate,
+            &submitter.key(),
+            gate_token_account.as_deref(),
+            gate_metadata_account.as_deref(),
+        ) @ ErrorCode::GateNotSatisfied
+    )]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(
+        mut,
+        seeds = [
+            b"opinion_epoch",
+            opinion_account.key().as_ref(),
+            epoch_account.epoch.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+    pub submitter: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8,
+        seeds = [b"voted", epoch_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    pub gate_metadata_account: Option<Account<'info, MetadataAccount>>,
+}
+
+#[queue_computation_accounts("reveal_opinion_threshold", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct RevealOpinionThreshold<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPINION_THRESHOLD)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureOpinionComparison<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account_a: Account<'info, OpinionAccount>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account_b: Account<'info, OpinionAccount>,
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 8 + 1 + 1,
+        seeds = [
+            b"opinion_comparison",
+            opinion_account_a.key().as_ref(),
+            opinion_account_b.key().as_ref(),
+        ],
+        bump
+    )]
+    pub comparison_account: Account<'info, OpinionComparisonAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("compare_opinion_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CompareOpinionStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_COMPARE_OPINION_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account_a: Account<'info, OpinionAccount>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account_b: Account<'info, OpinionAccount>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"opinion_comparison",
+            opinion_account_a.key().as_ref(),
+            opinion_account_b.key().as_ref(),
+        ],
+        bump
+    )]
+    pub comparison_account: Account<'info, OpinionComparisonAccount>,
+}
+
+#[queue_computation_accounts("reencrypt_opinion_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct ReencryptOpinionStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REENCRYPT_OPINION_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 8 + 32 + 32 + 16 + 32 * 8,
+        seeds = [b"opinion_stats_result", opinion_account.key().as_ref()],
+        bump
+    )]
+    pub stats_result_account: Account<'info, EncryptedStatsResultAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[queue_computation_accounts("compare_opinion_response", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct CompareOpinionResponse<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_COMPARE_OPINION_RESPONSE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub submitter: Signer<'info>,
+    #[account(
+        seeds = [
+            b"response",
+            opinion_account.key().as_ref(),
+            submitter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub response_account: Account<'info, ResponseReceiptAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 32 + 32 + 16 + 32 * 2,
+        seeds = [
+            b"personal_comparison",
+            opinion_account.key().as_ref(),
+            submitter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub comparison_account: Account<'info, PersonalComparisonAccount>,
+}
+
+// Feedback account structures
+#[queue_computation_accounts("init_feedback_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, post_id: u64)]
+pub struct InitFeedbackStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_FEEDBACK_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub post_account: Account<'info, OpinionPostAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 16 + 32 * 8 + 16 + 32 * 8 + 8 + 8 + 8 + 1 + 8 * 5 + 1 + 1,
+        seeds = [b"feedback_stats", post_account.key().as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+}
+
+#[queue_computation_accounts("submit_feedback", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, post_id: u64)]
+pub struct SubmitFeedbackResponse<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_FEEDBACK)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"opinion_post", post_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = gate::is_satisfied(
+            &post_account.gate,
+            &submitter.key(),
+            gate_token_account.as_deref(),
+            gate_metadata_account.as_deref(),
+        ) @ ErrorCode::GateNotSatisfied
+    )]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    pub submitter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"feedback_stats", post_account.key().as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8,
+        seeds = [b"voted", post_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    pub gate_metadata_account: Option<Account<'info, MetadataAccount>>,
+}
+
+#[queue_computation_accounts("reveal_feedback_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, post_id: u64)]
+pub struct RevealFeedbackStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FEEDBACK_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = author @ ErrorCode::Unauthorized)]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    pub author: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"feedback_stats", post_account.key().as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+}
+
+#[queue_computation_accounts("rotate_feedback_window", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, post_id: u64)]
+pub struct RotateFeedbackWindow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROTATE_FEEDBACK_WINDOW)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = author @ ErrorCode::Unauthorized)]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    pub author: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"feedback_stats", post_account.key().as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 16 + 32 * 8 + 32 + 8 + 8 + 1,
+        seeds = [
+            b"feedback_epoch",
+            feedback_stats_account.key().as_ref(),
+            feedback_stats_account.current_epoch.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+}
+
+#[queue_computation_accounts("reveal_feedback_epoch_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, post_id: u64, epoch: u64)]
+pub struct RevealFeedbackEpochStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FEEDBACK_EPOCH_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = author @ ErrorCode::Unauthorized)]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    pub author: Signer<'info>,
+    #[account(
+        seeds = [b"feedback_stats", post_account.key().as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(
+        seeds = [b"feedback_epoch", feedback_stats_account.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+}
+
+#[queue_computation_accounts("reveal_feedback_threshold", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, post_id: u64)]
+pub struct RevealFeedbackThreshold<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FEEDBACK_THRESHOLD)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, has_one = author @ ErrorCode::Unauthorized)]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    pub author: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"feedback_stats", post_account.key().as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+}
+
+#[queue_computation_accounts("reencrypt_feedback_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, post_id: u64)]
+pub struct ReencryptFeedbackStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REENCRYPT_FEEDBACK_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = author @ ErrorCode::Unauthorized)]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    #[account(
+        seeds = [b"feedback_stats", post_account.key().as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 8 + 32 + 32 + 16 + 32 * 8,
+        seeds = [b"feedback_stats_result", post_account.key().as_ref()],
+        bump
+    )]
+    pub stats_result_account: Account<'info, EncryptedStatsResultAccount>,
+    pub author: Signer<'info>,
+}
+
+// Forecast account structures
+#[derive(Accounts)]
+pub struct CreateForecast<'info> {
+    #[account(mut, seeds = [b"registry"], bump)]
+    pub registry_account: Account<'info, RegistryAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 8 + 16 + 32 * 2 + 32 + 8 + 8 + 4 + 1 + 1 + 1
+            + (32 + 1) * FORECAST_LEADERBOARD_SIZE
+            + 4 + MAX_FORECAST_QUESTION_LEN + 1,
+        seeds = [b"forecast", registry_account.next_forecast_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub forecast_account: Account<'info, ForecastAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(forecast_id: u64)]
+pub struct ResolveForecast<'info> {
+    #[account(
+        mut,
+        seeds = [b"forecast", forecast_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub forecast_account: Account<'info, ForecastAccount>,
+    pub authority: Signer<'info>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+#[queue_computation_accounts("init_forecast_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, forecast_id: u64)]
+pub struct InitForecastStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_FORECAST_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"forecast", forecast_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub forecast_account: Account<'info, ForecastAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[queue_computation_accounts("submit_forecast", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, forecast_id: u64, pseudonym: [u8; 32])]
+pub struct SubmitForecast<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_FORECAST)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"forecast", forecast_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub forecast_account: Account<'info, ForecastAccount>,
+    pub submitter: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8,
+        seeds = [b"voted", forecast_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 32 + 32 + 16 + 32 + 1 + 16 + 32 * 2,
+        seeds = [
+            b"forecast_entry",
+            forecast_account.key().as_ref(),
+            pseudonym.as_ref(),
+        ],
+        bump
+    )]
+    pub entry_account: Account<'info, ForecastEntryAccount>,
+}
+
+#[queue_computation_accounts("reveal_forecast_mean", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, forecast_id: u64)]
+pub struct RevealForecastMean<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FORECAST_MEAN)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"forecast", forecast_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub forecast_account: Account<'info, ForecastAccount>,
+}
+
+#[queue_computation_accounts("score_forecast", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, forecast_id: u64)]
+pub struct ScoreForecast<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
         mut,
         address = derive_execpool_pda!()
     )]
@@ -593,12 +6716,12 @@ pub struct InitVoteStats<'info> {
     pub executing_pool: UncheckedAccount<'info>,
     #[account(
         mut,
-        address = derive_comp_pda!(0u64)
+        address = derive_comp_pda!(computation_offset)
     )]
     /// CHECK: computation_account, checked by the arcium program
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_VOTE_STATS)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SCORE_FORECAST)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -617,12 +6740,137 @@ pub struct InitVoteStats<'info> {
     pub clock_account: Account<'info, ClockAccount>,
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        seeds = [b"forecast", forecast_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub forecast_account: Account<'info, ForecastAccount>,
+    #[account(
+        seeds = [
+            b"forecast_entry",
+            forecast_account.key().as_ref(),
+            entry_account.pseudonym.as_ref(),
+        ],
+        bump
+    )]
+    pub entry_account: Account<'info, ForecastEntryAccount>,
 }
 
-#[queue_computation_accounts("vote", payer)]
+// Batched ballot account structures
+#[derive(Accounts)]
+pub struct EnqueueVote<'info> {
+    #[account(
+        constraint = gate::is_satisfied(
+            &poll_account.gate,
+            &submitter.key(),
+            gate_token_account.as_deref(),
+            gate_metadata_account.as_deref(),
+        ) @ ErrorCode::GateNotSatisfied
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(
+        init_if_needed,
+        payer = submitter,
+        space = BALLOT_QUEUE_SPACE,
+        seeds = [b"ballot_queue", poll_account.key().as_ref()],
+        bump
+    )]
+    pub queue_account: Account<'info, BallotQueueAccount>,
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8,
+        seeds = [b"voted", poll_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    pub system_program: Program<'info, System>,
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    pub gate_metadata_account: Option<Account<'info, MetadataAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct EnqueueOpinionResponse<'info> {
+    #[account(
+        constraint = gate::is_satisfied(
+            &opinion_account.gate,
+            &submitter.key(),
+            gate_token_account.as_deref(),
+            gate_metadata_account.as_deref(),
+        ) @ ErrorCode::GateNotSatisfied
+    )]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(
+        init_if_needed,
+        payer = submitter,
+        space = BALLOT_QUEUE_SPACE,
+        seeds = [b"ballot_queue", opinion_account.key().as_ref()],
+        bump
+    )]
+    pub queue_account: Account<'info, BallotQueueAccount>,
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8,
+        seeds = [b"voted", opinion_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    pub system_program: Program<'info, System>,
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    pub gate_metadata_account: Option<Account<'info, MetadataAccount>>,
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct EnqueueFeedbackResponse<'info> {
+    #[account(
+        seeds = [b"opinion_post", post_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = gate::is_satisfied(
+            &post_account.gate,
+            &submitter.key(),
+            gate_token_account.as_deref(),
+            gate_metadata_account.as_deref(),
+        ) @ ErrorCode::GateNotSatisfied
+    )]
+    pub post_account: Account<'info, OpinionPostAccount>,
+    #[account(
+        seeds = [b"feedback_stats", post_account.key().as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8,
+        seeds = [b"voted", post_account.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub voted_account: Account<'info, VotedAccount>,
+    #[account(
+        init_if_needed,
+        payer = submitter,
+        space = BALLOT_QUEUE_SPACE,
+        seeds = [b"ballot_queue", feedback_stats_account.key().as_ref()],
+        bump
+    )]
+    pub queue_account: Account<'info, BallotQueueAccount>,
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    pub gate_metadata_account: Option<Account<'info, MetadataAccount>>,
+}
+
+#[queue_computation_accounts("aggregate_vote_batch", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, poll_id: u64)]
-pub struct Vote<'info> {
+pub struct AggregateVoteBatch<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -657,7 +6905,7 @@ pub struct Vote<'info> {
     /// CHECK: computation_account, checked by the arcium program
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AGGREGATE_VOTE_BATCH)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -676,14 +6924,20 @@ pub struct Vote<'info> {
     pub clock_account: Account<'info, ClockAccount>,
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
     pub poll_account: Account<'info, PollAccount>,
-    pub submitter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"ballot_queue", poll_account.key().as_ref()],
+        bump
+    )]
+    pub queue_account: Account<'info, BallotQueueAccount>,
 }
 
-#[queue_computation_accounts("reveal_result", payer)]
+#[queue_computation_accounts("aggregate_opinion_batch", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64, poll_id: u64)]
-pub struct RevealResult<'info> {
+#[instruction(computation_offset: u64, opinion_id: u64)]
+pub struct AggregateOpinionBatch<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -718,7 +6972,74 @@ pub struct RevealResult<'info> {
     /// CHECK: computation_account, checked by the arcium program
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_RESULT)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AGGREGATE_OPINION_BATCH)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(
+        mut,
+        seeds = [b"ballot_queue", opinion_account.key().as_ref()],
+        bump
+    )]
+    pub queue_account: Account<'info, BallotQueueAccount>,
+}
+
+#[queue_computation_accounts("aggregate_feedback_batch", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, post_id: u64)]
+pub struct AggregateFeedbackBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AGGREGATE_FEEDBACK_BATCH)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -727,512 +7048,964 @@ pub struct RevealResult<'info> {
     )]
     pub cluster_account: Account<'info, Cluster>,
     #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub post_account: Account<'info, OpinionPostAccount>,
+    #[account(
+        mut,
+        seeds = [b"feedback_stats", post_account.key().as_ref()],
+        bump
+    )]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(
+        mut,
+        seeds = [b"ballot_queue", feedback_stats_account.key().as_ref()],
+        bump
+    )]
+    pub queue_account: Account<'info, BallotQueueAccount>,
+}
+
+// Callback account structures
+#[callback_accounts("init_vote_stats")]
+#[derive(Accounts)]
+pub struct InitVoteStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("vote")]
+#[derive(Accounts)]
+pub struct VoteCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    /// CHECK: submitter, only used for the vote event
+    pub submitter: UncheckedAccount<'info>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_result")]
+#[derive(Accounts)]
+pub struct RevealResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_RESULT)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("init_option_vote_stats")]
+#[derive(Accounts)]
+pub struct InitOptionVoteStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_OPTION_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("vote_option")]
+#[derive(Accounts)]
+pub struct VoteOptionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_OPTION)
     )]
-    pub pool_account: Account<'info, FeePool>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    /// CHECK: submitter, only used for the vote event
+    pub submitter: UncheckedAccount<'info>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_option_result")]
+#[derive(Accounts)]
+pub struct RevealOptionResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPTION_RESULT)
     )]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
     pub poll_account: Account<'info, PollAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
 }
 
-// Account structures - Opinions
-#[queue_computation_accounts("init_opinion_stats", payer)]
+#[callback_accounts("init_opinion_stats")]
 #[derive(Accounts)]
-#[instruction(opinion_id: u64)]
-pub struct InitOpinionStats<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+pub struct InitOpinionStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_OPINION_STATS)
     )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("submit_opinion")]
+#[derive(Accounts)]
+pub struct SubmitOpinionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_mxe_pda!()
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_OPINION)
     )]
-    pub mxe_account: Account<'info, MXEAccount>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    /// CHECK: submitter, only used for the submission event
+    pub submitter: UncheckedAccount<'info>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("submit_eligible_opinion")]
+#[derive(Accounts)]
+pub struct SubmitEligibleOpinionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_mempool_pda!()
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_ELIGIBLE_OPINION)
     )]
-    /// CHECK: mempool_account, checked by the arcium program
-    pub mempool_account: UncheckedAccount<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    /// CHECK: submitter, only used for the submission event
+    pub submitter: UncheckedAccount<'info>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_rejected_responses")]
+#[derive(Accounts)]
+pub struct RevealRejectedResponsesCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_execpool_pda!()
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_REJECTED_RESPONSES)
     )]
-    /// CHECK: executing_pool, checked by the arcium program
-    pub executing_pool: UncheckedAccount<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_opinion_stats")]
+#[derive(Accounts)]
+pub struct RevealOpinionStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_comp_pda!(0u64)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPINION_STATS)
     )]
-    /// CHECK: computation_account, checked by the arcium program
-    pub computation_account: UncheckedAccount<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("rotate_opinion_window")]
+#[derive(Accounts)]
+pub struct RotateOpinionWindowCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_OPINION_STATS)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROTATE_OPINION_WINDOW)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(mut)]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_opinion_epoch_stats")]
+#[derive(Accounts)]
+pub struct RevealOpinionEpochStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPINION_EPOCH_STATS)
     )]
-    pub cluster_account: Account<'info, Cluster>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub opinion_account: Account<'info, OpinionAccount>,
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("init_pulse_stats")]
+#[derive(Accounts)]
+pub struct InitPulseStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_PULSE_STATS)
     )]
-    pub pool_account: Account<'info, FeePool>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("submit_pulse_opinion")]
+#[derive(Accounts)]
+pub struct SubmitPulseOpinionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_PULSE_OPINION)
     )]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(mut)]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_opinion_threshold")]
+#[derive(Accounts)]
+pub struct RevealOpinionThresholdCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPINION_THRESHOLD)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
 }
 
-#[queue_computation_accounts("submit_opinion", payer)]
+#[callback_accounts("compare_opinion_stats")]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64, opinion_id: u64)]
-pub struct SubmitOpinionResponse<'info> {
+pub struct CompareOpinionStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_COMPARE_OPINION_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub opinion_account_a: Account<'info, OpinionAccount>,
+    pub opinion_account_b: Account<'info, OpinionAccount>,
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub comparison_account: Account<'info, OpinionComparisonAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reencrypt_opinion_stats")]
+#[derive(Accounts)]
+pub struct ReencryptOpinionStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REENCRYPT_OPINION_STATS)
     )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(mut)]
+    pub stats_result_account: Account<'info, EncryptedStatsResultAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("compare_opinion_response")]
+#[derive(Accounts)]
+pub struct CompareOpinionResponseCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_mxe_pda!()
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_COMPARE_OPINION_RESPONSE)
     )]
-    pub mxe_account: Account<'info, MXEAccount>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(mut)]
+    pub comparison_account: Account<'info, PersonalComparisonAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("init_feedback_stats")]
+#[derive(Accounts)]
+pub struct InitFeedbackStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_mempool_pda!()
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_FEEDBACK_STATS)
     )]
-    /// CHECK: mempool_account, checked by the arcium program
-    pub mempool_account: UncheckedAccount<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("submit_feedback")]
+#[derive(Accounts)]
+pub struct SubmitFeedbackCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_execpool_pda!()
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_FEEDBACK)
     )]
-    /// CHECK: executing_pool, checked by the arcium program
-    pub executing_pool: UncheckedAccount<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub post_account: Account<'info, OpinionPostAccount>,
+    /// CHECK: submitter, only used for the submission event
+    pub submitter: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_feedback_stats")]
+#[derive(Accounts)]
+pub struct RevealFeedbackStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FEEDBACK_STATS)
     )]
-    /// CHECK: computation_account, checked by the arcium program
-    pub computation_account: UncheckedAccount<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub post_account: Account<'info, OpinionPostAccount>,
+    #[account(mut)]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("rotate_feedback_window")]
+#[derive(Accounts)]
+pub struct RotateFeedbackWindowCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_OPINION)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROTATE_FEEDBACK_WINDOW)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(mut)]
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_feedback_epoch_stats")]
+#[derive(Accounts)]
+pub struct RevealFeedbackEpochStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FEEDBACK_EPOCH_STATS)
     )]
-    pub cluster_account: Account<'info, Cluster>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    pub epoch_account: Account<'info, StatsEpochAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_feedback_threshold")]
+#[derive(Accounts)]
+pub struct RevealFeedbackThresholdCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FEEDBACK_THRESHOLD)
     )]
-    pub pool_account: Account<'info, FeePool>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub post_account: Account<'info, OpinionPostAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reencrypt_feedback_stats")]
+#[derive(Accounts)]
+pub struct ReencryptFeedbackStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REENCRYPT_FEEDBACK_STATS)
     )]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub opinion_account: Account<'info, OpinionAccount>,
-    pub submitter: Signer<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub post_account: Account<'info, OpinionPostAccount>,
+    #[account(mut)]
+    pub stats_result_account: Account<'info, EncryptedStatsResultAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
 }
 
-#[queue_computation_accounts("reveal_opinion_stats", payer)]
+#[callback_accounts("init_forecast_stats")]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64, opinion_id: u64)]
-pub struct RevealOpinionStats<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+pub struct InitForecastStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_FORECAST_STATS)
     )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub forecast_account: Account<'info, ForecastAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("submit_forecast")]
+#[derive(Accounts)]
+pub struct SubmitForecastCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_mxe_pda!()
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_FORECAST)
     )]
-    pub mxe_account: Account<'info, MXEAccount>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub forecast_account: Account<'info, ForecastAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("reveal_forecast_mean")]
+#[derive(Accounts)]
+pub struct RevealForecastMeanCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_mempool_pda!()
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FORECAST_MEAN)
     )]
-    /// CHECK: mempool_account, checked by the arcium program
-    pub mempool_account: UncheckedAccount<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub forecast_account: Account<'info, ForecastAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("score_forecast")]
+#[derive(Accounts)]
+pub struct ScoreForecastCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_execpool_pda!()
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SCORE_FORECAST)
     )]
-    /// CHECK: executing_pool, checked by the arcium program
-    pub executing_pool: UncheckedAccount<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub forecast_account: Account<'info, ForecastAccount>,
+    #[account(mut)]
+    pub entry_account: Account<'info, ForecastEntryAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("aggregate_vote_batch")]
+#[derive(Accounts)]
+pub struct AggregateVoteBatchCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AGGREGATE_VOTE_BATCH)
     )]
-    /// CHECK: computation_account, checked by the arcium program
-    pub computation_account: UncheckedAccount<'info>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub poll_account: Account<'info, PollAccount>,
+    #[account(mut)]
+    pub queue_account: Account<'info, BallotQueueAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("aggregate_opinion_batch")]
+#[derive(Accounts)]
+pub struct AggregateOpinionBatchCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPINION_STATS)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AGGREGATE_OPINION_BATCH)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub opinion_account: Account<'info, OpinionAccount>,
+    #[account(mut)]
+    pub queue_account: Account<'info, BallotQueueAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[callback_accounts("aggregate_feedback_batch")]
+#[derive(Accounts)]
+pub struct AggregateFeedbackBatchCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
     #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AGGREGATE_FEEDBACK_BATCH)
     )]
-    pub cluster_account: Account<'info, Cluster>,
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut)]
+    pub feedback_stats_account: Account<'info, FeedbackStatsAccount>,
+    #[account(mut)]
+    pub queue_account: Account<'info, BallotQueueAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+// Init computation definition account structures
+#[init_computation_definition_accounts("init_vote_stats", payer)]
+#[derive(Accounts)]
+pub struct InitInitVoteStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Account<'info, FeePool>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        address = derive_mxe_pda!()
     )]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
     pub arcium_program: Program<'info, Arcium>,
-    pub opinion_account: Account<'info, OpinionAccount>,
+    pub system_program: Program<'info, System>,
 }
 
-// Feedback account structures
-#[queue_computation_accounts("init_feedback_stats", payer)]
+#[init_computation_definition_accounts("vote", payer)]
 #[derive(Accounts)]
-#[instruction(post_id: u64)]
-pub struct InitFeedbackStats<'info> {
+pub struct InitVoteCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Account<'info, MXEAccount>,
     #[account(
         mut,
-        address = derive_mempool_pda!()
+        address = derive_mxe_pda!()
     )]
-    /// CHECK: mempool_account, checked by the arcium program
-    pub mempool_account: UncheckedAccount<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reveal_result", payer)]
+#[derive(Accounts)]
+pub struct InitRevealResultCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = derive_execpool_pda!()
+        address = derive_mxe_pda!()
     )]
-    /// CHECK: executing_pool, checked by the arcium program
-    pub executing_pool: UncheckedAccount<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("init_opinion_stats", payer)]
+#[derive(Accounts)]
+pub struct InitInitOpinionStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = derive_comp_pda!(0u64)
-    )]
-    /// CHECK: computation_account, checked by the arcium program
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_FEEDBACK_STATS)
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("submit_opinion", payer)]
+#[derive(Accounts)]
+pub struct InitSubmitOpinionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = derive_cluster_pda!(mxe_account)
+        address = derive_mxe_pda!()
     )]
-    pub cluster_account: Account<'info, Cluster>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reveal_opinion_stats", payer)]
+#[derive(Accounts)]
+pub struct InitRevealOpinionStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Account<'info, FeePool>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        address = derive_mxe_pda!()
     )]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
     pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("submit_feedback", payer)]
+#[init_computation_definition_accounts("rotate_opinion_window", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64, post_id: u64)]
-pub struct SubmitFeedbackResponse<'info> {
+pub struct InitRotateOpinionWindowCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Account<'info, MXEAccount>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program
-    pub executing_pool: UncheckedAccount<'info>,
     #[account(
         mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_FEEDBACK)
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reveal_opinion_epoch_stats", payer)]
+#[derive(Accounts)]
+pub struct InitRevealOpinionEpochStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = derive_cluster_pda!(mxe_account)
+        address = derive_mxe_pda!()
     )]
-    pub cluster_account: Account<'info, Cluster>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("init_pulse_stats", payer)]
+#[derive(Accounts)]
+pub struct InitInitPulseStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Account<'info, FeePool>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        address = derive_mxe_pda!()
     )]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
     pub arcium_program: Program<'info, Arcium>,
-    pub post_account: Account<'info, OpinionPostAccount>,
-    pub submitter: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("reveal_feedback_stats", payer)]
+#[init_computation_definition_accounts("submit_pulse_opinion", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64, post_id: u64)]
-pub struct RevealFeedbackStats<'info> {
+pub struct InitSubmitPulseOpinionCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
+        mut,
         address = derive_mxe_pda!()
     )]
-    pub mxe_account: Account<'info, MXEAccount>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reveal_opinion_threshold", payer)]
+#[derive(Accounts)]
+pub struct InitRevealOpinionThresholdCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = derive_mempool_pda!()
+        address = derive_mxe_pda!()
     )]
-    /// CHECK: mempool_account, checked by the arcium program
-    pub mempool_account: UncheckedAccount<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("compare_opinion_stats", payer)]
+#[derive(Accounts)]
+pub struct InitCompareOpinionStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = derive_execpool_pda!()
+        address = derive_mxe_pda!()
     )]
-    /// CHECK: executing_pool, checked by the arcium program
-    pub executing_pool: UncheckedAccount<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reencrypt_opinion_stats", payer)]
+#[derive(Accounts)]
+pub struct InitReencryptOpinionStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FEEDBACK_STATS)
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("compare_opinion_response", payer)]
+#[derive(Accounts)]
+pub struct InitCompareOpinionResponseCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = derive_cluster_pda!(mxe_account)
+        address = derive_mxe_pda!()
     )]
-    pub cluster_account: Account<'info, Cluster>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("init_feedback_stats", payer)]
+#[derive(Accounts)]
+pub struct InitInitFeedbackStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Account<'info, FeePool>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        address = derive_mxe_pda!()
     )]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
     pub arcium_program: Program<'info, Arcium>,
-    pub post_account: Account<'info, OpinionPostAccount>,
+    pub system_program: Program<'info, System>,
 }
 
-// Callback account structures
-#[callback_accounts("init_vote_stats")]
+#[init_computation_definition_accounts("submit_feedback", payer)]
 #[derive(Accounts)]
-pub struct InitVoteStatsCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+pub struct InitSubmitFeedbackCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_VOTE_STATS)
+        mut,
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("vote")]
+#[init_computation_definition_accounts("reveal_feedback_stats", payer)]
 #[derive(Accounts)]
-pub struct VoteCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+pub struct InitRevealFeedbackStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE)
+        mut,
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    pub poll_account: Account<'info, PollAccount>,
-    pub submitter: Signer<'info>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("reveal_result")]
+#[init_computation_definition_accounts("rotate_feedback_window", payer)]
 #[derive(Accounts)]
-pub struct RevealResultCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+pub struct InitRotateFeedbackWindowCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_RESULT)
+        mut,
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    pub poll_account: Account<'info, PollAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("init_opinion_stats")]
+#[init_computation_definition_accounts("reveal_feedback_epoch_stats", payer)]
 #[derive(Accounts)]
-pub struct InitOpinionStatsCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+pub struct InitRevealFeedbackEpochStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_OPINION_STATS)
+        mut,
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("submit_opinion")]
+#[init_computation_definition_accounts("reveal_feedback_threshold", payer)]
 #[derive(Accounts)]
-pub struct SubmitOpinionCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+pub struct InitRevealFeedbackThresholdCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_OPINION)
+        mut,
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    pub opinion_account: Account<'info, OpinionAccount>,
-    pub submitter: Signer<'info>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("reveal_opinion_stats")]
+#[init_computation_definition_accounts("reencrypt_feedback_stats", payer)]
 #[derive(Accounts)]
-pub struct RevealOpinionStatsCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+pub struct InitReencryptFeedbackStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_OPINION_STATS)
+        mut,
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    pub opinion_account: Account<'info, OpinionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("init_feedback_stats")]
+#[init_computation_definition_accounts("init_forecast_stats", payer)]
 #[derive(Accounts)]
-pub struct InitFeedbackStatsCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+pub struct InitInitForecastStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_FEEDBACK_STATS)
+        mut,
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("submit_feedback")]
+#[init_computation_definition_accounts("submit_forecast", payer)]
 #[derive(Accounts)]
-pub struct SubmitFeedbackCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+pub struct InitSubmitForecastCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_FEEDBACK)
+        mut,
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    pub post_account: Account<'info, OpinionPostAccount>,
-    pub submitter: Signer<'info>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("reveal_feedback_stats")]
+#[init_computation_definition_accounts("reveal_forecast_mean", payer)]
 #[derive(Accounts)]
-pub struct RevealFeedbackStatsCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+pub struct InitRevealForecastMeanCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_FEEDBACK_STATS)
+        mut,
+        address = derive_mxe_pda!()
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    pub post_account: Account<'info, OpinionPostAccount>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, initialized by the arcium program
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-// Init computation definition account structures
-#[init_computation_definition_accounts("init_vote_stats", payer)]
+#[init_computation_definition_accounts("score_forecast", payer)]
 #[derive(Accounts)]
-pub struct InitInitVoteStatsCompDef<'info> {
+pub struct InitScoreForecastCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1247,9 +8020,9 @@ pub struct InitInitVoteStatsCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("vote", payer)]
+#[init_computation_definition_accounts("aggregate_vote_batch", payer)]
 #[derive(Accounts)]
-pub struct InitVoteCompDef<'info> {
+pub struct InitAggregateVoteBatchCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1264,9 +8037,9 @@ pub struct InitVoteCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("reveal_result", payer)]
+#[init_computation_definition_accounts("aggregate_opinion_batch", payer)]
 #[derive(Accounts)]
-pub struct InitRevealResultCompDef<'info> {
+pub struct InitAggregateOpinionBatchCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1281,9 +8054,9 @@ pub struct InitRevealResultCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("init_opinion_stats", payer)]
+#[init_computation_definition_accounts("aggregate_feedback_batch", payer)]
 #[derive(Accounts)]
-pub struct InitInitOpinionStatsCompDef<'info> {
+pub struct InitAggregateFeedbackBatchCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1298,9 +8071,9 @@ pub struct InitInitOpinionStatsCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("submit_opinion", payer)]
+#[init_computation_definition_accounts("submit_eligible_opinion", payer)]
 #[derive(Accounts)]
-pub struct InitSubmitOpinionCompDef<'info> {
+pub struct InitSubmitEligibleOpinionCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1315,9 +8088,9 @@ pub struct InitSubmitOpinionCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("reveal_opinion_stats", payer)]
+#[init_computation_definition_accounts("reveal_rejected_responses", payer)]
 #[derive(Accounts)]
-pub struct InitRevealOpinionStatsCompDef<'info> {
+pub struct InitRevealRejectedResponsesCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1332,9 +8105,9 @@ pub struct InitRevealOpinionStatsCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("init_feedback_stats", payer)]
+#[init_computation_definition_accounts("init_option_vote_stats", payer)]
 #[derive(Accounts)]
-pub struct InitInitFeedbackStatsCompDef<'info> {
+pub struct InitInitOptionVoteStatsCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1349,9 +8122,9 @@ pub struct InitInitFeedbackStatsCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("submit_feedback", payer)]
+#[init_computation_definition_accounts("vote_option", payer)]
 #[derive(Accounts)]
-pub struct InitSubmitFeedbackCompDef<'info> {
+pub struct InitVoteOptionCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1366,9 +8139,9 @@ pub struct InitSubmitFeedbackCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("reveal_feedback_stats", payer)]
+#[init_computation_definition_accounts("reveal_option_result", payer)]
 #[derive(Accounts)]
-pub struct InitRevealFeedbackStatsCompDef<'info> {
+pub struct InitRevealOptionResultCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1393,7 +8166,26 @@ pub struct VoteSubmittedEvent {
 #[event]
 pub struct PollResultRevealedEvent {
     pub poll_id: u64,
-    pub majority_yes: bool,
+    pub quorum_met: bool,
+    pub passed: bool,  // Always false when quorum was not met
+    pub overflowed: bool,
+    pub pass_threshold: PassThreshold,
+}
+
+#[event]
+pub struct OpinionCreatedEvent {
+    pub opinion_id: u64,
+    pub authority: Pubkey,
+    pub deadline: i64,
+    pub period: i64,
+    pub eligible_attributes: u64,
+    pub allowlisted: bool,
+}
+
+#[event]
+pub struct AttributeCredentialIssuedEvent {
+    pub opinion_id: u64,
+    pub respondent: Pubkey,
 }
 
 #[event]
@@ -1405,14 +8197,95 @@ pub struct OpinionSubmittedEvent {
 #[event]
 pub struct OpinionStatsRevealedEvent {
     pub opinion_id: u64,
-    pub total_responses: u32,
+    pub total_responses: u64,
+    pub average_rating: u8,
+    pub rating_distribution: [u64; 5],
+    pub overflowed: bool,
+}
+
+#[event]
+pub struct OpinionEpochSnapshotEvent {
+    pub opinion_id: u64,
+    pub epoch: u64,
+    pub started_at: i64,
+    pub ended_at: i64,
+}
+
+#[event]
+pub struct OpinionEpochStatsRevealedEvent {
+    pub opinion_id: u64,
+    pub epoch: u64,
+    pub total_responses: u64,
     pub average_rating: u8,
-    pub rating_distribution: [u32; 5],
+    pub rating_distribution: [u64; 5],
+    pub overflowed: bool,
+}
+
+#[event]
+pub struct PulseResponseSubmittedEvent {
+    pub opinion_id: u64,
+    pub epoch: u64,
+}
+
+#[event]
+pub struct OpinionThresholdRevealedEvent {
+    pub opinion_id: u64,
+    pub threshold: u8,
+    pub above_threshold: bool,
+}
+
+#[event]
+pub struct OpinionComparisonConfiguredEvent {
+    pub opinion_id_a: u64,
+    pub opinion_id_b: u64,
+    pub margin: u8,
+}
+
+#[event]
+pub struct OpinionComparisonRevealedEvent {
+    pub opinion_id_a: u64,
+    pub opinion_id_b: u64,
+    pub margin: u8,
+    /// 0 = no significant difference, 1 = A rated higher, 2 = B rated higher
+    pub outcome: u8,
+}
+
+#[event]
+pub struct OpinionStatsReencryptedEvent {
+    pub opinion_id: u64,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct PersonalComparisonReadyEvent {
+    pub opinion_id: u64,
+    pub respondent: Pubkey,
+}
+
+#[event]
+pub struct TopicCreatedEvent {
+    pub topic_id: u64,
+    pub creator: Pubkey,
+    pub name: String,
+}
+
+#[event]
+pub struct TopicUpdatedEvent {
+    pub topic_id: u64,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct PseudonymRegisteredEvent {
+    pub persona: Pubkey,
+    pub topic_id: Option<u64>,
+    pub created_at: i64,
 }
 
 #[event]
 pub struct OpinionPostCreatedEvent {
     pub post_id: u64,
+    pub topic_id: Option<u64>,
     pub author: Pubkey,
 }
 
@@ -1421,6 +8294,28 @@ pub struct CommentAddedEvent {
     pub comment_id: u64,
     pub post_id: u64,
     pub author: Pubkey,
+    pub parent_comment_id: Option<u64>,
+    pub depth: u16,
+}
+
+#[event]
+pub struct OpinionPostDeletedEvent {
+    pub post_id: u64,
+    pub author: Pubkey,
+    pub total_comments: u32,
+}
+
+#[event]
+pub struct CommentDeletedEvent {
+    pub comment_id: u64,
+    pub post_id: u64,
+    pub author: Pubkey,
+}
+
+#[event]
+pub struct FeedbackStatsClosedEvent {
+    pub post_id: u64,
+    pub total_feedback: u64,
 }
 
 #[event]
@@ -1432,9 +8327,135 @@ pub struct FeedbackSubmittedEvent {
 #[event]
 pub struct FeedbackStatsRevealedEvent {
     pub post_id: u64,
-    pub total_feedback: u32,
+    pub total_feedback: u64,
+    pub average_rating: u8,
+    pub rating_distribution: [u64; 5],
+    pub overflowed: bool,
+}
+
+#[event]
+pub struct FeedbackEpochSnapshotEvent {
+    pub post_id: u64,
+    pub epoch: u64,
+    pub started_at: i64,
+    pub ended_at: i64,
+}
+
+#[event]
+pub struct FeedbackEpochStatsRevealedEvent {
+    pub post_id: u64,
+    pub epoch: u64,
+    pub total_feedback: u64,
     pub average_rating: u8,
-    pub rating_distribution: [u32; 5],
+    pub rating_distribution: [u64; 5],
+    pub overflowed: bool,
+}
+
+#[event]
+pub struct FeedbackThresholdRevealedEvent {
+    pub post_id: u64,
+    pub threshold: u8,
+    pub above_threshold: bool,
+}
+
+#[event]
+pub struct FeedbackStatsReencryptedEvent {
+    pub post_id: u64,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct ForecastCreatedEvent {
+    pub forecast_id: u64,
+    pub authority: Pubkey,
+    pub deadline: i64,
+}
+
+#[event]
+pub struct ForecastSubmittedEvent {
+    pub forecast_id: u64,
+}
+
+#[event]
+pub struct ForecastMeanRevealedEvent {
+    pub forecast_id: u64,
+    pub total_forecasts: u64,
+    pub mean_probability: u8,
+}
+
+#[event]
+pub struct ForecastResolvedEvent {
+    pub forecast_id: u64,
+    pub outcome: bool,
+}
+
+#[event]
+pub struct ForecastScoredEvent {
+    pub forecast_id: u64,
+    pub pseudonym: [u8; 32],
+    pub brier_bucket: u8,
+}
+
+#[event]
+pub struct BallotEnqueuedEvent {
+    pub subject: Pubkey,
+}
+
+#[event]
+pub struct BallotBatchAggregatedEvent {
+    pub subject: Pubkey,
+    pub count: u8,
+}
+
+#[event]
+pub struct RejectedResponsesRevealedEvent {
+    pub opinion_id: u64,
+    pub rejected: u64,
+}
+
+#[event]
+pub struct PollCreatedEvent {
+    pub poll_id: u64,
+    pub authority: Pubkey,
+    pub allowlisted: bool,
+    pub quorum: Quorum,
+    pub pass_threshold: PassThreshold,
+    pub option_count: u8,
+}
+
+#[event]
+pub struct OptionPollResultRevealedEvent {
+    pub poll_id: u64,
+    pub round: u8,
+    pub majority: bool,
+    pub leading_option: u8,
+    pub runner_up_option: u8,  // NO_OPTION unless a runoff is needed
+    pub runoff_needed: bool,
+    pub overflowed: bool,
+}
+
+#[event]
+pub struct RunoffOpenedEvent {
+    pub poll_id: u64,
+    pub runoff_poll_id: u64,
+    pub round: u8,
+    pub candidates: [u8; 2],
+}
+
+#[event]
+pub struct ContentAppendedEvent {
+    pub account: Pubkey,
+    pub len: u32,
+    pub complete: bool,
+}
+
+#[event]
+pub struct OpinionPostEditedEvent {
+    pub post_id: u64,
+    pub version: u32,
+    pub edited_at: i64,
+    pub previous_hash: [u8; 32],
+    pub history_hash: [u8; 32],
 }
 
 #[error_code]
@@ -1443,5 +8464,107 @@ pub enum ErrorCode {
     AbortedComputation,
     #[msg("Cluster not set")]
     ClusterNotSet,
+    #[msg("Threshold must be between 1.0 and 5.0 (scaled by 10)")]
+    InvalidThreshold,
+    #[msg("No rating threshold was set at creation")]
+    NoThreshold,
+    #[msg("The threshold result has already been revealed")]
+    ThresholdAlreadyRevealed,
+    #[msg("The poll result has already been revealed")]
+    ResultAlreadyRevealed,
+    #[msg("Encrypted statistics have already been initialized")]
+    StatsAlreadyInitialized,
+    #[msg("Encrypted statistics have not been initialized yet")]
+    StatsNotInitialized,
+    #[msg("Signer is not authorized for this account")]
+    Unauthorized,
+    #[msg("Comparison margin must be at most 4.0 (scaled by 10)")]
+    InvalidMargin,
+    #[msg("Cannot compare an opinion survey with itself")]
+    SameOpinionAccount,
+    #[msg("Opinion surveys must be paired in ascending opinion_id order")]
+    OpinionPairOrder,
+    #[msg("This comparison has already been revealed")]
+    ComparisonAlreadyRevealed,
+    #[msg("Question is too long")]
+    QuestionTooLong,
+    #[msg("Deadline must be in the future")]
+    InvalidDeadline,
+    #[msg("The submission deadline has passed")]
+    DeadlinePassed,
+    #[msg("The submission deadline has not been reached")]
+    DeadlineNotReached,
+    #[msg("The forecast has already been resolved")]
+    ForecastAlreadyResolved,
+    #[msg("The forecast has not been resolved yet")]
+    ForecastNotResolved,
+    #[msg("This forecast has already been scored")]
+    AlreadyScored,
+    #[msg("Revealed rating average is out of range")]
+    AverageOutOfRange,
+    #[msg("Title is too long")]
+    TitleTooLong,
+    #[msg("Period must not be negative")]
+    InvalidPeriod,
+    #[msg("Epoch is not valid for this survey at this time")]
+    InvalidEpoch,
+    #[msg("Recurring surveys advance epochs automatically")]
+    RecurringSurvey,
+    #[msg("Responses to a recurring survey go through submit_pulse_response")]
+    PulseResponseRequired,
+    #[msg("Ballot queue is full; aggregate the pending batch first")]
+    BallotQueueFull,
+    #[msg("Ballot queue is empty")]
+    BallotQueueEmpty,
+    #[msg("A batch from this queue is already being aggregated")]
+    BatchInFlight,
+    #[msg("This survey requires the private eligibility check")]
+    EligibilityCheckRequired,
+    #[msg("This survey has no eligibility rule")]
+    NoEligibilityRule,
+    #[msg("Submitter is not on the allowlist")]
+    NotAllowlisted,
+    #[msg("Submitter does not hold the required token or NFT")]
+    GateNotSatisfied,
+    #[msg("Quorum must require at least one ballot and at most 100% of eligible voters")]
+    InvalidQuorum,
+    #[msg("Pass threshold must be a fraction between 1/2 and 1")]
+    InvalidPassThreshold,
+    #[msg("Multi-option polls need between 2 and 8 options")]
+    InvalidOptionCount,
+    #[msg("This instruction does not apply to this kind of poll")]
+    WrongPollKind,
+    #[msg("No runoff is pending for this poll")]
+    NoRunoffPending,
+    #[msg("A runoff has already been opened for this poll")]
+    RunoffAlreadyOpened,
+    #[msg("Runoff chain is too long")]
+    InvalidRunoff,
+    #[msg("Encrypted content exceeds the maximum length")]
+    ContentTooLong,
+    #[msg("Encrypted content does not match its declared length")]
+    ContentLengthMismatch,
+    #[msg("Post content has not been fully uploaded")]
+    ContentIncomplete,
+    #[msg("Off-chain content needs a URI of at most 200 bytes, a length, and no on-chain body")]
+    InvalidOffChainContent,
+    #[msg("An edit must replace the title, topic or content")]
+    EmptyEdit,
+    #[msg("Post has reached the maximum number of edits")]
+    TooManyEdits,
+    #[msg("Reply exceeds the maximum thread depth")]
+    ThreadTooDeep,
+    #[msg("Authors cannot earn reputation from their own posts or comments")]
+    SelfReputation,
+    #[msg("Pseudonym is registered for a different topic")]
+    PseudonymTopicMismatch,
+    #[msg("Topic name must be 1 to 50 bytes")]
+    InvalidTopicName,
+    #[msg("Topic description is too long")]
+    TopicDescriptionTooLong,
+    #[msg("Too many topic moderators")]
+    TooManyModerators,
+    #[msg("Topic accounts must be passed exactly when the post has a topic")]
+    TopicAccountsMismatch,
 }
 }
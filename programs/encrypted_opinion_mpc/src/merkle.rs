@@ -0,0 +1,86 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::keccak::hashv;
+
+/// Longest accepted proof, enough for allowlists of about a million members.
+pub const MAX_PROOF_DEPTH: usize = 20;
+
+// Domain separation so an inner node can never be presented as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hashes an allowlisted member into a Merkle leaf.
+///
+/// The member is usually the submitter's wallet, but any 32-byte commitment (such as a
+/// nullifier commitment) can be allowlisted the same way.
+pub fn leaf(member: &[u8; 32]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, member]).to_bytes()
+}
+
+/// Hashes two sibling nodes into their parent.
+///
+/// Siblings are sorted first, so proofs do not need to carry left/right positions.
+pub fn parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Checks that `leaf` is included under `root` using the sibling hashes in `proof`.
+pub fn verify(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    if proof.len() > MAX_PROOF_DEPTH {
+        return false;
+    }
+    proof.iter().fold(leaf, |node, sibling| parent(&node, sibling)) == *root
+}
+
+/// Whether `member` may participate in a subject with the given optional allowlist root.
+///
+/// Subjects without a root are open to everyone.
+pub fn is_allowlisted(root: Option<[u8; 32]>, member: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    match root {
+        Some(root) => verify(&root, leaf(&member.to_bytes()), proof),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root_of(start: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+        proof.iter().fold(start, |node, sibling| parent(&node, sibling))
+    }
+
+    // A four-member tree; returns the root and the proof for member `[1; 32]`
+    fn tree() -> ([u8; 32], Vec<[u8; 32]>) {
+        let leaves = [[1u8; 32], [2; 32], [3; 32], [4; 32]].map(|member| leaf(&member));
+        let right = parent(&leaves[2], &leaves[3]);
+        let proof = vec![leaves[1], right];
+        (root_of(leaves[0], &proof), proof)
+    }
+
+    #[test]
+    fn accepts_valid_proof() {
+        let (root, proof) = tree();
+        assert!(verify(&root, leaf(&[1; 32]), &proof));
+        assert!(is_allowlisted(Some(root), &Pubkey::new_from_array([1; 32]), &proof));
+    }
+
+    #[test]
+    fn rejects_wrong_sibling() {
+        let (root, mut proof) = tree();
+        proof[0] = leaf(&[3; 32]);
+        assert!(!verify(&root, leaf(&[1; 32]), &proof));
+    }
+
+    #[test]
+    fn rejects_proofs_deeper_than_max() {
+        let start = leaf(&[1; 32]);
+        let proof = vec![[7u8; 32]; MAX_PROOF_DEPTH + 1];
+        assert!(verify(
+            &root_of(start, &proof[..MAX_PROOF_DEPTH]),
+            start,
+            &proof[..MAX_PROOF_DEPTH]
+        ));
+        assert!(!verify(&root_of(start, &proof), start, &proof));
+    }
+}
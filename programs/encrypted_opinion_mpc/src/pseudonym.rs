@@ -0,0 +1,48 @@
+use anchor_lang::solana_program::hash::hashv;
+
+// Domain separation so persona seeds never collide with other hashes of the user secret
+const PERSONA_PREFIX: &[u8] = b"opinion-persona";
+const TOPIC_PERSONA_PREFIX: &[u8] = b"opinion-topic-persona";
+
+/// Derives the ed25519 seed of a general-purpose persona.
+///
+/// `index` lets one secret hold several unrelated personas. The seed becomes the persona
+/// keypair with `Keypair::from_seed` (or `Keypair.fromSeed` in web3.js).
+pub fn persona_seed(secret: &[u8; 32], index: u32) -> [u8; 32] {
+    hashv(&[PERSONA_PREFIX, secret, &index.to_le_bytes()]).to_bytes()
+}
+
+/// Derives the ed25519 seed of the persona used for one topic.
+///
+/// The same secret and topic always give the same persona, while personas of different
+/// topics are unrelated without the secret. Register it with `register_pseudonym` and the
+/// topic id so it is only accepted on that topic's posts and threads.
+pub fn topic_persona_seed(secret: &[u8; 32], topic_id: u64) -> [u8; 32] {
+    hashv(&[TOPIC_PERSONA_PREFIX, secret, &topic_id.to_le_bytes()]).to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; 32] = [9; 32];
+
+    #[test]
+    fn derivation_is_deterministic() {
+        assert_eq!(persona_seed(&SECRET, 0), persona_seed(&SECRET, 0));
+        assert_eq!(topic_persona_seed(&SECRET, 7), topic_persona_seed(&SECRET, 7));
+    }
+
+    #[test]
+    fn seeds_differ_across_indices_and_topics() {
+        assert_ne!(persona_seed(&SECRET, 0), persona_seed(&SECRET, 1));
+        assert_ne!(topic_persona_seed(&SECRET, 0), topic_persona_seed(&SECRET, 1));
+        assert_ne!(persona_seed(&[8; 32], 0), persona_seed(&SECRET, 0));
+    }
+
+    #[test]
+    fn general_and_topic_personas_are_separated() {
+        // The domain prefixes keep persona index 3 apart from topic 3
+        assert_ne!(persona_seed(&SECRET, 3), topic_persona_seed(&SECRET, 3));
+    }
+}
@@ -0,0 +1,51 @@
+/// A rating average in fixed point with one decimal place, e.g. `35` is 3.5.
+///
+/// Averages are computed from counters revealed by MPC, which are `u64` and may be
+/// saturated, so the arithmetic is done in `u128` and checked before narrowing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RatingX10(u8);
+
+impl RatingX10 {
+    /// Computes `sum / count` scaled by 10, rounding down.
+    ///
+    /// An empty set of ratings averages to zero. Returns `None` if the average does not
+    /// fit in a `u8`, i.e. is above 25.5. The circuits only count ratings of 1-5, so this
+    /// means the counters are inconsistent, for example after one of them saturated.
+    pub fn from_sum(sum: u64, count: u64) -> Option<Self> {
+        if count == 0 {
+            return Some(Self(0));
+        }
+        let scaled = (sum as u128).checked_mul(10)? / count as u128;
+        u8::try_from(scaled).ok().map(Self)
+    }
+
+    pub fn get(self) -> u8 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_ratings_average_to_zero() {
+        assert_eq!(RatingX10::from_sum(0, 0).unwrap().get(), 0);
+        assert_eq!(RatingX10::from_sum(42, 0).unwrap().get(), 0);
+    }
+
+    #[test]
+    fn rounds_down_to_one_decimal() {
+        // 11 / 3 = 3.666..
+        assert_eq!(RatingX10::from_sum(11, 3).unwrap().get(), 36);
+        assert_eq!(RatingX10::from_sum(7, 2).unwrap().get(), 35);
+        assert_eq!(RatingX10::from_sum(5, 1).unwrap().get(), 50);
+    }
+
+    #[test]
+    fn rejects_averages_above_u8() {
+        assert_eq!(RatingX10::from_sum(255, 10).unwrap().get(), 255);
+        assert_eq!(RatingX10::from_sum(256, 10), None);
+        assert_eq!(RatingX10::from_sum(u64::MAX, 1), None);
+    }
+}
@@ -0,0 +1,341 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { EncryptedOpinionMpc } from "../target/types/encrypted_opinion_mpc";
+import { expect } from "chai";
+import { randomBytes } from "crypto";
+import {
+  getClusterAccAddress,
+  getCompDefAccAddress,
+  getCompDefAccOffset,
+  getComputationAccAddress,
+  getExecutingPoolAccAddress,
+  getMempoolAccAddress,
+  getMXEAccAddress,
+} from "@arcium-hq/client";
+
+// These paths are rejected before any computation is queued, so no MPC cluster has to
+// finish a job for the tests to pass.
+describe("Access Control Tests", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.EncryptedOpinionMpc as Program<EncryptedOpinionMpc>;
+  const wallet = provider.wallet.publicKey;
+  // Signs in place of the account owner; the provider wallet still pays the fees
+  const outsider = Keypair.generate();
+
+  // Global devnet cluster, as in the voting tests
+  const CLUSTER_OFFSET = 1078779259;
+
+  const [registryAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from("registry")],
+    program.programId
+  );
+  const registry = () => program.account.registryAccount.fetch(registryAccount);
+  const pda = (...seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+  const le64 = (n: anchor.BN) => n.toArrayLike(Buffer, "le", 8);
+  const now = () => Math.floor(Date.now() / 1000);
+
+  // Arcium accounts shared by every queued computation
+  const queueAccounts = (circuit: string, computationOffset: anchor.BN) => ({
+    computationAccount: getComputationAccAddress(program.programId, computationOffset),
+    clusterAccount: getClusterAccAddress(CLUSTER_OFFSET),
+    mxeAccount: getMXEAccAddress(program.programId),
+    mempoolAccount: getMempoolAccAddress(program.programId),
+    executingPool: getExecutingPoolAccAddress(program.programId),
+    compDefAccount: getCompDefAccAddress(
+      program.programId,
+      Buffer.from(getCompDefAccOffset(circuit)).readUInt32LE()
+    ),
+  });
+
+  // Resolves with the program error code the transaction failed with
+  const errorCode = async (tx: Promise<string>) => {
+    try {
+      await tx;
+    } catch (error: any) {
+      return error.error?.errorCode?.code ?? error.message;
+    }
+    return "transaction succeeded";
+  };
+
+  const createPoll = async (deadline: number) => {
+    const pollId = (await registry()).nextPollId;
+    const pollAccount = pda(Buffer.from("poll"), le64(pollId));
+    await program.methods
+      .createPoll(
+        "Should the treasury fund audits?",
+        null,
+        null,
+        { ballots: { "0": new anchor.BN(1) } },
+        { numerator: 1, denominator: 2 },
+        0,
+        false,
+        new anchor.BN(deadline)
+      )
+      .accountsPartial({ registryAccount, pollAccount, authority: wallet })
+      .rpc();
+    return { pollId, pollAccount };
+  };
+
+  const createOpinion = async (period: number) => {
+    const opinionId = (await registry()).nextOpinionId;
+    const opinionAccount = pda(Buffer.from("opinion"), le64(opinionId));
+    await program.methods
+      .createOpinion(
+        "How was the onboarding?",
+        Array.from(randomBytes(32)),
+        new anchor.BN(now() + 3600),
+        new anchor.BN(period),
+        new anchor.BN(0),
+        null,
+        null,
+        35
+      )
+      .accountsPartial({ registryAccount, opinionAccount, authority: wallet })
+      .rpc();
+    return { opinionId, opinionAccount };
+  };
+
+  before(async () => {
+    const existing = await provider.connection.getAccountInfo(registryAccount);
+    if (!existing) {
+      await program.methods
+        .initRegistry()
+        .accountsPartial({ registryAccount, payer: wallet })
+        .rpc();
+    }
+
+    const compDefs = [
+      "initInitVoteStatsCompDef",
+      "initRevealResultCompDef",
+      "initRevealOpinionStatsCompDef",
+      "initRevealOpinionThresholdCompDef",
+      "initCompareOpinionStatsCompDef",
+      "initInitPulseStatsCompDef",
+      "initSubmitForecastCompDef",
+    ];
+    for (const name of compDefs) {
+      try {
+        await (program.methods as any)[name]().rpc();
+      } catch (error: any) {
+        if (!error.message?.includes("already in use")) throw error;
+      }
+    }
+  });
+
+  it("✅ Rejects vote stats initialization by a non-authority", async () => {
+    const { pollId, pollAccount } = await createPoll(0);
+    const offset = new anchor.BN(randomBytes(8));
+
+    const code = await errorCode(
+      program.methods
+        .initVoteStats(offset, pollId)
+        .accountsPartial({
+          ...queueAccounts("init_vote_stats", offset),
+          payer: wallet,
+          pollAccount,
+          authority: outsider.publicKey,
+        })
+        .signers([outsider])
+        .rpc()
+    );
+    expect(code).to.equal("Unauthorized");
+  });
+
+  it("✅ Rejects a poll reveal by a non-authority", async () => {
+    const { pollId, pollAccount } = await createPoll(0);
+    const offset = new anchor.BN(randomBytes(8));
+
+    const code = await errorCode(
+      program.methods
+        .revealResult(offset, pollId)
+        .accountsPartial({
+          ...queueAccounts("reveal_result", offset),
+          payer: wallet,
+          pollAccount,
+          authority: outsider.publicKey,
+        })
+        .signers([outsider])
+        .rpc()
+    );
+    expect(code).to.equal("Unauthorized");
+  });
+
+  it("✅ Rejects a poll reveal before the deadline", async () => {
+    const { pollId, pollAccount } = await createPoll(now() + 3600);
+    const offset = new anchor.BN(randomBytes(8));
+
+    const code = await errorCode(
+      program.methods
+        .revealResult(offset, pollId)
+        .accountsPartial({
+          ...queueAccounts("reveal_result", offset),
+          payer: wallet,
+          pollAccount,
+          authority: wallet,
+        })
+        .rpc()
+    );
+    expect(code).to.equal("DeadlineNotReached");
+
+    const poll = await program.account.pollAccount.fetch(pollAccount);
+    expect(poll.resultRevealed).to.equal(false);
+  });
+
+  it("✅ Rejects opinion stats and threshold reveals by a non-authority", async () => {
+    const { opinionId, opinionAccount } = await createOpinion(0);
+
+    const statsOffset = new anchor.BN(randomBytes(8));
+    const statsCode = await errorCode(
+      program.methods
+        .revealOpinionStats(statsOffset, opinionId)
+        .accountsPartial({
+          ...queueAccounts("reveal_opinion_stats", statsOffset),
+          payer: wallet,
+          opinionAccount,
+          authority: outsider.publicKey,
+        })
+        .signers([outsider])
+        .rpc()
+    );
+    expect(statsCode).to.equal("Unauthorized");
+
+    const thresholdOffset = new anchor.BN(randomBytes(8));
+    const thresholdCode = await errorCode(
+      program.methods
+        .revealOpinionThreshold(thresholdOffset, opinionId)
+        .accountsPartial({
+          ...queueAccounts("reveal_opinion_threshold", thresholdOffset),
+          payer: wallet,
+          opinionAccount,
+          authority: outsider.publicKey,
+        })
+        .signers([outsider])
+        .rpc()
+    );
+    expect(thresholdCode).to.equal("Unauthorized");
+  });
+
+  it("✅ Fixes comparison margins per ordered pair of surveys", async () => {
+    const first = await createOpinion(0);
+    const second = await createOpinion(0);
+    const comparisonAccount = (a: PublicKey, b: PublicKey) =>
+      pda(Buffer.from("opinion_comparison"), a.toBuffer(), b.toBuffer());
+
+    const reversed = await errorCode(
+      program.methods
+        .configureOpinionComparison(5)
+        .accountsPartial({
+          payer: wallet,
+          opinionAccountA: second.opinionAccount,
+          opinionAccountB: first.opinionAccount,
+          authority: wallet,
+          comparisonAccount: comparisonAccount(second.opinionAccount, first.opinionAccount),
+        })
+        .rpc()
+    );
+    expect(reversed).to.equal("OpinionPairOrder");
+
+    const unauthorized = await errorCode(
+      program.methods
+        .configureOpinionComparison(5)
+        .accountsPartial({
+          payer: wallet,
+          opinionAccountA: first.opinionAccount,
+          opinionAccountB: second.opinionAccount,
+          authority: outsider.publicKey,
+          comparisonAccount: comparisonAccount(first.opinionAccount, second.opinionAccount),
+        })
+        .signers([outsider])
+        .rpc()
+    );
+    expect(unauthorized).to.equal("Unauthorized");
+
+    const pair = comparisonAccount(first.opinionAccount, second.opinionAccount);
+    await program.methods
+      .configureOpinionComparison(5)
+      .accountsPartial({
+        payer: wallet,
+        opinionAccountA: first.opinionAccount,
+        opinionAccountB: second.opinionAccount,
+        authority: wallet,
+        comparisonAccount: pair,
+      })
+      .rpc();
+    const comparison = await program.account.opinionComparisonAccount.fetch(pair);
+    expect(comparison.margin).to.equal(5);
+    expect(comparison.revealed).to.equal(false);
+
+    // The margin cannot be changed once the pair is configured
+    const reconfigured = await errorCode(
+      program.methods
+        .configureOpinionComparison(40)
+        .accountsPartial({
+          payer: wallet,
+          opinionAccountA: first.opinionAccount,
+          opinionAccountB: second.opinionAccount,
+          authority: wallet,
+          comparisonAccount: pair,
+        })
+        .rpc()
+    );
+    expect(reconfigured).to.not.equal("transaction succeeded");
+  });
+
+  it("✅ Rejects pulse period initialization by a non-authority", async () => {
+    const { opinionId, opinionAccount } = await createOpinion(600);
+    const epoch = new anchor.BN(0);
+    const offset = new anchor.BN(randomBytes(8));
+
+    const code = await errorCode(
+      program.methods
+        .initPulseStats(offset, opinionId, epoch)
+        .accountsPartial({
+          ...queueAccounts("init_pulse_stats", offset),
+          payer: wallet,
+          opinionAccount,
+          authority: outsider.publicKey,
+          epochAccount: pda(Buffer.from("opinion_epoch"), opinionAccount.toBuffer(), le64(epoch)),
+        })
+        .signers([outsider])
+        .rpc()
+    );
+    expect(code).to.equal("Unauthorized");
+  });
+
+  it("✅ Rejects forecasts before the forecast stats are initialized", async () => {
+    const forecastId = (await registry()).nextForecastId;
+    const forecastAccount = pda(Buffer.from("forecast"), le64(forecastId));
+    await program.methods
+      .createForecast("Will the upgrade ship this quarter?", new anchor.BN(now() + 3600))
+      .accountsPartial({ registryAccount, forecastAccount, authority: wallet })
+      .rpc();
+
+    const pseudonym = randomBytes(32);
+    const offset = new anchor.BN(randomBytes(8));
+    const code = await errorCode(
+      program.methods
+        .submitForecast(
+          offset,
+          forecastId,
+          Array.from(pseudonym),
+          Array.from(randomBytes(32)),
+          Array.from(randomBytes(32)),
+          new anchor.BN(randomBytes(16))
+        )
+        .accountsPartial({
+          ...queueAccounts("submit_forecast", offset),
+          payer: wallet,
+          forecastAccount,
+          submitter: wallet,
+          votedAccount: pda(Buffer.from("voted"), forecastAccount.toBuffer(), wallet.toBuffer()),
+          entryAccount: pda(Buffer.from("forecast_entry"), forecastAccount.toBuffer(), pseudonym),
+        })
+        .rpc()
+    );
+    expect(code).to.equal("StatsNotInitialized");
+  });
+});
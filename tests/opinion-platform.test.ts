@@ -11,20 +11,45 @@ describe("Encrypted Opinion Platform Tests", () => {
 
   const program = anchor.workspace.EncryptedOpinionMpc as Program<EncryptedOpinionMpc>;
 
+  // Wraps bytes in the on-chain ciphertext envelope (simulating client-side encryption)
+  const blob = (bytes: Buffer) => ({
+    nonce: Array.from(Buffer.alloc(24)),
+    authTag: Array.from(Buffer.alloc(16)),
+    ciphertext: bytes,
+  });
+
+  // Post ids are handed out by the global registry
+  const [registryAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from("registry")],
+    program.programId
+  );
+  const nextPostId = async () =>
+    (await program.account.registryAccount.fetch(registryAccount)).nextPostId;
+  const postIds: anchor.BN[] = [];
+
+  before(async () => {
+    const existing = await provider.connection.getAccountInfo(registryAccount);
+    if (!existing) {
+      await program.methods
+        .initRegistry()
+        .accountsPartial({
+          registryAccount,
+          payer: provider.wallet.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+  });
+
   it("✅ Creates an encrypted opinion post", async () => {
     console.log("\n🚀 Testing: Create Opinion Post");
     
-    const postId = new anchor.BN(1);
+    const postId = await nextPostId();
     
     // Create encrypted content (simulating client-side encryption)
-    const title = Buffer.alloc(32);
-    Buffer.from("My Anonymous Opinion").copy(title);
-    
-    const content = Buffer.alloc(128);
-    Buffer.from("This is my encrypted opinion content that remains private").copy(content);
-    
-    const topic = Buffer.alloc(16);
-    Buffer.from("Privacy").copy(topic);
+    const title = Buffer.from("My Anonymous Opinion");
+    const content = Buffer.from("This is my encrypted opinion content that remains private");
+    const topic = Buffer.from("Privacy");
 
     // Derive the PDA for the post account
     const [postAccount] = PublicKey.findProgramAddressSync(
@@ -38,14 +63,20 @@ describe("Encrypted Opinion Platform Tests", () => {
     // Create the opinion post
     const tx = await program.methods
       .createOpinionPost(
-        postId,
-        Array.from(title),
-        Array.from(content),
-        Array.from(topic)
+        blob(title),
+        blob(topic),
+        blob(content),
+        null,
+        content.length,
+        null,
+        null,
+        null
       )
       .accountsPartial({
+        registryAccount,
         postAccount: postAccount,
         author: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
         clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
       })
@@ -57,6 +88,7 @@ describe("Encrypted Opinion Platform Tests", () => {
     const postData = await program.account.opinionPostAccount.fetch(postAccount);
     
     expect(postData.postId.toNumber()).to.equal(postId.toNumber());
+    postIds.push(postId);
     expect(postData.author.toString()).to.equal(provider.wallet.publicKey.toString());
     expect(postData.totalComments).to.equal(0);
     expect(postData.totalFeedback).to.equal(0);
@@ -70,12 +102,11 @@ describe("Encrypted Opinion Platform Tests", () => {
   it("✅ Adds an encrypted comment to a post", async () => {
     console.log("\n🚀 Testing: Add Comment");
     
-    const postId = new anchor.BN(1);
-    const commentId = new anchor.BN(1);
+    const postId = postIds[0];
+    const commentId = new anchor.BN(0);  // first comment on the post
     
     // Create encrypted comment content
-    const commentContent = Buffer.alloc(64);
-    Buffer.from("This is an encrypted comment on the post").copy(commentContent);
+    const commentContent = Buffer.from("This is an encrypted comment on the post");
 
     // Derive PDAs
     const [postAccount] = PublicKey.findProgramAddressSync(
@@ -84,7 +115,7 @@ describe("Encrypted Opinion Platform Tests", () => {
     );
 
     const [commentAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from("comment"), commentId.toArrayLike(Buffer, "le", 8)],
+      [Buffer.from("comment"), postAccount.toBuffer(), commentId.toArrayLike(Buffer, "le", 8)],
       program.programId
     );
 
@@ -93,11 +124,12 @@ describe("Encrypted Opinion Platform Tests", () => {
 
     // Add the comment
     const tx = await program.methods
-      .addComment(commentId, Array.from(commentContent))
+      .addComment(blob(commentContent), null, commentContent.length)
       .accountsPartial({
         commentAccount: commentAccount,
         postAccount: postAccount,
         author: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
         clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
       })
@@ -126,18 +158,12 @@ describe("Encrypted Opinion Platform Tests", () => {
   it("✅ Creates multiple opinion posts", async () => {
     console.log("\n🚀 Testing: Multiple Posts");
     
-    const postIds = [2, 3, 4];
-    
-    for (const postIdNum of postIds) {
-      const postId = new anchor.BN(postIdNum);
-      const title = Buffer.alloc(32);
-      Buffer.from(`Post ${postIdNum}`).copy(title);
-      
-      const content = Buffer.alloc(128);
-      Buffer.from(`Content for post ${postIdNum}`).copy(content);
-      
-      const topic = Buffer.alloc(16);
-      Buffer.from(`Topic${postIdNum}`).copy(topic);
+    for (let i = 0; i < 3; i++) {
+      const postId = await nextPostId();
+      const postIdNum = postId.toNumber();
+      const title = Buffer.from(`Post ${postIdNum}`);
+      const content = Buffer.from(`Content for post ${postIdNum}`);
+      const topic = Buffer.from(`Topic${postIdNum}`);
 
       const [postAccount] = PublicKey.findProgramAddressSync(
         [Buffer.from("opinion_post"), postId.toArrayLike(Buffer, "le", 8)],
@@ -146,18 +172,25 @@ describe("Encrypted Opinion Platform Tests", () => {
 
       await program.methods
         .createOpinionPost(
-          postId,
-          Array.from(title),
-          Array.from(content),
-          Array.from(topic)
+          blob(title),
+          blob(topic),
+          blob(content),
+          null,
+          content.length,
+          null,
+          null,
+          null
         )
         .accountsPartial({
+          registryAccount,
           postAccount: postAccount,
           author: provider.wallet.publicKey,
+          payer: provider.wallet.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
         })
         .rpc();
+      postIds.push(postId);
 
       console.log(`✅ Post ${postIdNum} created`);
     }
@@ -169,16 +202,15 @@ describe("Encrypted Opinion Platform Tests", () => {
     console.log("\n🚀 Testing: Multiple Comments");
     
     const comments = [
-      { commentId: 2, postId: 2 },
-      { commentId: 3, postId: 2 },
-      { commentId: 4, postId: 3 },
+      { commentId: 0, postId: postIds[1] },
+      { commentId: 1, postId: postIds[1] },
+      { commentId: 0, postId: postIds[2] },
     ];
     
-    for (const { commentId: commentIdNum, postId: postIdNum } of comments) {
+    for (const { commentId: commentIdNum, postId } of comments) {
       const commentId = new anchor.BN(commentIdNum);
-      const postId = new anchor.BN(postIdNum);
-      const commentContent = Buffer.alloc(64);
-      Buffer.from(`Comment ${commentIdNum} on post ${postIdNum}`).copy(commentContent);
+      const postIdNum = postId.toNumber();
+      const commentContent = Buffer.from(`Comment ${commentIdNum} on post ${postIdNum}`);
 
       const [postAccount] = PublicKey.findProgramAddressSync(
         [Buffer.from("opinion_post"), postId.toArrayLike(Buffer, "le", 8)],
@@ -186,16 +218,17 @@ describe("Encrypted Opinion Platform Tests", () => {
       );
 
       const [commentAccount] = PublicKey.findProgramAddressSync(
-        [Buffer.from("comment"), commentId.toArrayLike(Buffer, "le", 8)],
+        [Buffer.from("comment"), postAccount.toBuffer(), commentId.toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
       await program.methods
-        .addComment(commentId, Array.from(commentContent))
+        .addComment(blob(commentContent), null, commentContent.length)
         .accountsPartial({
           commentAccount: commentAccount,
           postAccount: postAccount,
           author: provider.wallet.publicKey,
+          payer: provider.wallet.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
         })
@@ -204,16 +237,16 @@ describe("Encrypted Opinion Platform Tests", () => {
       console.log(`✅ Comment ${commentIdNum} added to post ${postIdNum}`);
     }
     
-    // Verify post 2 has 2 comments
+    // Verify the second post has 2 comments
     const [post2Account] = PublicKey.findProgramAddressSync(
-      [Buffer.from("opinion_post"), new anchor.BN(2).toArrayLike(Buffer, "le", 8)],
+      [Buffer.from("opinion_post"), postIds[1].toArrayLike(Buffer, "le", 8)],
       program.programId
     );
     const post2Data = await program.account.opinionPostAccount.fetch(post2Account);
     expect(post2Data.totalComments).to.equal(2);
     
     console.log("✅ All comments verified!");
-    console.log("   - Post 2 has", post2Data.totalComments, "comments");
+    console.log("   - Post", postIds[1].toNumber(), "has", post2Data.totalComments, "comments");
   });
 
   it("✅ Program info and summary", async () => {
@@ -80,9 +80,10 @@ describe("Voting System MPC Tests", () => {
     // Initialize vote stats
     try {
       const tx = await program.methods
-        .initVoteStats(pollId)
+        .initVoteStats(initComputationOffset, pollId)
         .accounts({
           payer: provider.wallet.publicKey,
+          authority: provider.wallet.publicKey,
           signPdaAccount: signPDA,
           mxeAccount: MXE_ADDRESS,
           clusterAccount: CLUSTER_ACCOUNT,
@@ -195,6 +196,7 @@ describe("Voting System MPC Tests", () => {
           feePoolAccount: ARCIUM_FEE_POOL,
           arciumProgram: ARCIUM_PROGRAM,
           systemProgram: SystemProgram.programId,
+          authority: provider.wallet.publicKey,
         })
         .rpc();
       console.log("✅ Results revealed! Transaction:", tx);